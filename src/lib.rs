@@ -10,9 +10,11 @@ mod v1
 
 mod v2
 {
+  #[derive(Clone, Debug, PartialEq, Eq, Hash)]
   pub struct CasualPerson
   {
     pub name: String,
+    pub age: Option<u32>,
   }
 
   impl CasualPerson
@@ -21,6 +23,7 @@ mod v2
     {
       Self {
         name: name.to_string(),
+        age: None,
       }
     }
   }
@@ -31,15 +34,22 @@ mod v2
   }
 }
 
-mod v3
+pub mod v3
 {
+  use std::fmt::{
+    self,
+    Write,
+  };
+
   pub use crate::v2::CasualPerson;
 
+  #[derive(Clone, Debug, PartialEq, Eq, Hash)]
   pub struct FormalPerson
   {
     pub title: String,
     pub first_name: String,
     pub last_name: String,
+    pub age: Option<u32>,
   }
 
   impl FormalPerson
@@ -54,6 +64,7 @@ mod v3
         title: title.to_string(),
         first_name: first_name.to_string(),
         last_name: last_name.to_string(),
+        age: None,
       }
     }
   }
@@ -66,16 +77,18 @@ mod v3
     )
   }
 
+  #[derive(Clone, Debug, PartialEq, Eq, Hash)]
   pub struct Anonymous
   {
     pub id: u64,
+    pub age: Option<u32>,
   }
 
   impl Anonymous
   {
     pub fn new(id: u64) -> Self
     {
-      Self { id }
+      Self { id, age: None }
     }
   }
 
@@ -87,6 +100,42 @@ mod v3
   pub trait HasName
   {
     fn name(&self) -> String;
+
+    fn fmt_name(
+      &self,
+      f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+      f.write_str(&self.name())
+    }
+
+    fn initials(&self) -> String
+    {
+      self
+        .name()
+        .split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .map(|c| format!("{}.", c))
+        .collect()
+    }
+
+    fn name_family_first(&self) -> String
+    {
+      self.name()
+    }
+  }
+
+  pub struct DisplayName<'a, Person: ?Sized>(pub &'a Person);
+
+  impl<'a, Person: HasName + ?Sized> fmt::Display for DisplayName<'a, Person>
+  {
+    fn fmt(
+      &self,
+      f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+      self.0.fmt_name(f)
+    }
   }
 
   impl HasName for FormalPerson
@@ -95,6 +144,28 @@ mod v3
     {
       format!("{} {} {}", self.title, self.first_name, self.last_name)
     }
+
+    fn fmt_name(
+      &self,
+      f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+      write!(f, "{} {} {}", self.title, self.first_name, self.last_name)
+    }
+
+    fn name_family_first(&self) -> String
+    {
+      format!("{} {} {}", self.title, self.last_name, self.first_name)
+    }
+
+    fn initials(&self) -> String
+    {
+      [&self.first_name, &self.last_name]
+        .iter()
+        .filter_map(|word| word.chars().next())
+        .map(|c| format!("{}.", c))
+        .collect()
+    }
   }
 
   impl HasName for CasualPerson
@@ -111,6 +182,44 @@ mod v3
     {
       format!("Anonymous #{}", self.id)
     }
+
+    fn initials(&self) -> String
+    {
+      format!("#{}", self.id)
+    }
+  }
+
+  impl fmt::Display for FormalPerson
+  {
+    fn fmt(
+      &self,
+      f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+      self.fmt_name(f)
+    }
+  }
+
+  impl fmt::Display for CasualPerson
+  {
+    fn fmt(
+      &self,
+      f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+      self.fmt_name(f)
+    }
+  }
+
+  impl fmt::Display for Anonymous
+  {
+    fn fmt(
+      &self,
+      f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+      self.fmt_name(f)
+    }
   }
 
   fn greet_dyn(person: &dyn HasName) -> String
@@ -128,7 +237,19 @@ mod v3
 
   fn greet_generic<Person: HasName>(person: &Person) -> String
   {
-    format!("Hello, {}!", person.name())
+    let mut greeting = String::new();
+    greet_generic_into(person, &mut greeting);
+    greeting
+  }
+
+  pub fn greet_generic_into<Person: HasName>(
+    person: &Person,
+    buffer: &mut String,
+  )
+  {
+    buffer.clear();
+    write!(buffer, "Hello, {}!", DisplayName(person))
+      .expect("writing to a String cannot fail");
   }
 
   pub struct HasNameDict<Person>
@@ -149,12 +270,77 @@ mod v3
     format!("Hello, {}!", person.name())
   }
 
-  pub fn greet_many_generic<Person: HasName>(
-    persons: &Vec<Person>
-  ) -> Vec<String>
+  pub fn greet_many_generic<Person: HasName>(persons: &[Person])
+    -> Vec<String>
   {
     persons.iter().map(greet_generic).collect()
   }
+
+  pub trait HasSortKey: HasName
+  {
+    fn sort_key(&self) -> String;
+  }
+
+  impl HasSortKey for FormalPerson
+  {
+    fn sort_key(&self) -> String
+    {
+      self.last_name.clone()
+    }
+  }
+
+  impl HasSortKey for CasualPerson
+  {
+    fn sort_key(&self) -> String
+    {
+      self.name.clone()
+    }
+  }
+
+  impl HasSortKey for Anonymous
+  {
+    fn sort_key(&self) -> String
+    {
+      format!("{:020}", self.id)
+    }
+  }
+
+  pub fn greet_sorted<Person: HasSortKey>(persons: &[Person]) -> Vec<String>
+  {
+    let mut sorted: Vec<&Person> = persons.iter().collect();
+    sorted.sort_by_key(|person| person.sort_key());
+    sorted.into_iter().map(greet_generic).collect()
+  }
+
+  #[test]
+  fn test_fmt_name()
+  {
+    let formal = FormalPerson::new("Mr.", "John", "Smith");
+    let casual = CasualPerson::new("Alice");
+    let anon = Anonymous::new(8);
+
+    let mut buffer = String::new();
+
+    greet_generic_into(&formal, &mut buffer);
+    assert_eq!(buffer, format!("Hello, {}!", formal.name()));
+
+    greet_generic_into(&casual, &mut buffer);
+    assert_eq!(buffer, format!("Hello, {}!", casual.name()));
+
+    greet_generic_into(&anon, &mut buffer);
+    assert_eq!(buffer, format!("Hello, {}!", anon.name()));
+
+    assert_eq!(DisplayName(&formal).to_string(), formal.name());
+  }
+
+  #[test]
+  fn test_initials()
+  {
+    assert_eq!(FormalPerson::new("Mr.", "John", "Smith").initials(), "J.S.");
+    assert_eq!(CasualPerson::new("Alice").initials(), "A.");
+    assert_eq!(CasualPerson::new("ada lovelace").initials(), "a.l.");
+    assert_eq!(Anonymous::new(8).initials(), "#8");
+  }
 }
 
 mod v4
@@ -207,21 +393,251 @@ mod v4
   }
 }
 
-mod v5
+pub mod v5
 {
+  use std::fmt;
+
   use crate::v3::{
     Anonymous,
     CasualPerson,
     FormalPerson,
     HasName,
+    HasSortKey,
   };
 
+  #[derive(Clone, Debug, PartialEq, Eq, Hash)]
   pub enum Either<A, B>
   {
     Left(A),
     Right(B),
   }
 
+  impl<A, B> Either<A, B>
+  {
+    pub fn map_left<F, C>(
+      self,
+      f: F,
+    ) -> Either<C, B>
+    where
+      F: FnOnce(A) -> C,
+    {
+      match self {
+        Self::Left(a) => Either::Left(f(a)),
+        Self::Right(b) => Either::Right(b),
+      }
+    }
+
+    pub fn map_right<F, D>(
+      self,
+      f: F,
+    ) -> Either<A, D>
+    where
+      F: FnOnce(B) -> D,
+    {
+      match self {
+        Self::Left(a) => Either::Left(a),
+        Self::Right(b) => Either::Right(f(b)),
+      }
+    }
+
+    pub fn bimap<F, G, C, D>(
+      self,
+      f: F,
+      g: G,
+    ) -> Either<C, D>
+    where
+      F: FnOnce(A) -> C,
+      G: FnOnce(B) -> D,
+    {
+      match self {
+        Self::Left(a) => Either::Left(f(a)),
+        Self::Right(b) => Either::Right(g(b)),
+      }
+    }
+
+    pub fn map_left_ref<'a, F, C>(
+      &'a self,
+      f: F,
+    ) -> Either<C, &'a B>
+    where
+      F: FnOnce(&'a A) -> C,
+    {
+      match self {
+        Self::Left(a) => Either::Left(f(a)),
+        Self::Right(b) => Either::Right(b),
+      }
+    }
+
+    /// Folds both branches into a common result.
+    ///
+    /// ```
+    /// use generic_greet::{
+    ///   v3::{
+    ///     Anonymous,
+    ///     CasualPerson,
+    ///     HasName,
+    ///   },
+    ///   v5::Either,
+    /// };
+    ///
+    /// let person: Either<CasualPerson, Anonymous> =
+    ///   Either::Right(Anonymous::new(8));
+    ///
+    /// let name = person.either(|p| p.name(), |p| p.name());
+    ///
+    /// assert_eq!(name, "Anonymous #8");
+    /// ```
+    pub fn either<T, F, G>(
+      self,
+      f: F,
+      g: G,
+    ) -> T
+    where
+      F: FnOnce(A) -> T,
+      G: FnOnce(B) -> T,
+    {
+      match self {
+        Self::Left(a) => f(a),
+        Self::Right(b) => g(b),
+      }
+    }
+
+    /// Folds both borrowed branches into a common result, applying `f` or
+    /// `g` to whichever side is active.
+    ///
+    /// ```
+    /// use generic_greet::{
+    ///   v3::{
+    ///     CasualPerson,
+    ///     FormalPerson,
+    ///     HasName,
+    ///   },
+    ///   v5::AnyPerson,
+    /// };
+    ///
+    /// let persons = vec![
+    ///   AnyPerson::formal(FormalPerson::new("Mr.", "John", "Smith")),
+    ///   AnyPerson::casual(CasualPerson::new("Alice")),
+    /// ];
+    ///
+    /// for person in &persons {
+    ///   assert_eq!(
+    ///     person.0.either_ref(HasName::name, HasName::name),
+    ///     person.0.name()
+    ///   );
+    /// }
+    /// ```
+    pub fn either_ref<'a, T, F, G>(
+      &'a self,
+      f: F,
+      g: G,
+    ) -> T
+    where
+      F: FnOnce(&'a A) -> T,
+      G: FnOnce(&'a B) -> T,
+    {
+      match self {
+        Self::Left(a) => f(a),
+        Self::Right(b) => g(b),
+      }
+    }
+
+    pub fn swap(self) -> Either<B, A>
+    {
+      match self {
+        Self::Left(a) => Either::Right(a),
+        Self::Right(b) => Either::Left(b),
+      }
+    }
+
+    pub fn swap_ref(&self) -> Either<&B, &A>
+    {
+      self.as_ref().swap()
+    }
+
+    pub fn as_ref(&self) -> Either<&A, &B>
+    {
+      match self {
+        Self::Left(a) => Either::Left(a),
+        Self::Right(b) => Either::Right(b),
+      }
+    }
+
+    pub fn as_mut(&mut self) -> Either<&mut A, &mut B>
+    {
+      match self {
+        Self::Left(a) => Either::Left(a),
+        Self::Right(b) => Either::Right(b),
+      }
+    }
+
+    pub fn is_left(&self) -> bool
+    {
+      matches!(self, Self::Left(_))
+    }
+
+    pub fn is_right(&self) -> bool
+    {
+      matches!(self, Self::Right(_))
+    }
+
+    pub fn left(self) -> Option<A>
+    {
+      match self {
+        Self::Left(a) => Some(a),
+        Self::Right(_) => None,
+      }
+    }
+
+    pub fn right(self) -> Option<B>
+    {
+      match self {
+        Self::Left(_) => None,
+        Self::Right(b) => Some(b),
+      }
+    }
+
+    pub fn left_ref(&self) -> Option<&A>
+    {
+      self.as_ref().left()
+    }
+
+    pub fn right_ref(&self) -> Option<&B>
+    {
+      self.as_ref().right()
+    }
+  }
+
+  impl<A, B> Iterator for Either<A, B>
+  where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+  {
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item>
+    {
+      match self {
+        Self::Left(iter) => iter.next(),
+        Self::Right(iter) => iter.next(),
+      }
+    }
+  }
+
+  impl<A: fmt::Display, B: fmt::Display> fmt::Display for Either<A, B>
+  {
+    fn fmt(
+      &self,
+      f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+      match self {
+        Self::Left(a) => a.fmt(f),
+        Self::Right(b) => b.fmt(f),
+      }
+    }
+  }
+
   impl<A: HasName, B: HasName> HasName for Either<A, B>
   {
     fn name(&self) -> String
@@ -231,11 +647,31 @@ mod v5
         Self::Right(person) => person.name(),
       }
     }
+
+    fn name_family_first(&self) -> String
+    {
+      match self {
+        Self::Left(person) => person.name_family_first(),
+        Self::Right(person) => person.name_family_first(),
+      }
+    }
+  }
+
+  impl<A: HasSortKey, B: HasSortKey> HasSortKey for Either<A, B>
+  {
+    fn sort_key(&self) -> String
+    {
+      match self {
+        Self::Left(person) => person.sort_key(),
+        Self::Right(person) => person.sort_key(),
+      }
+    }
   }
 
   pub type AnyPersonGeneric =
     Either<FormalPerson, Either<CasualPerson, Anonymous>>;
 
+  #[derive(Clone, PartialEq, Eq, Hash)]
   pub struct AnyPerson(
     pub Either<FormalPerson, Either<CasualPerson, Anonymous>>,
   );
@@ -246,6 +682,38 @@ mod v5
     {
       self.0.name()
     }
+
+    fn name_family_first(&self) -> String
+    {
+      self.0.name_family_first()
+    }
+  }
+
+  impl HasSortKey for AnyPerson
+  {
+    fn sort_key(&self) -> String
+    {
+      self.0.sort_key()
+    }
+  }
+
+  impl fmt::Debug for AnyPerson
+  {
+    fn fmt(
+      &self,
+      f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+      match &self.0 {
+        Either::Left(person) => f.debug_tuple("Formal").field(person).finish(),
+        Either::Right(Either::Left(person)) => {
+          f.debug_tuple("Casual").field(person).finish()
+        }
+        Either::Right(Either::Right(person)) => {
+          f.debug_tuple("Anon").field(person).finish()
+        }
+      }
+    }
   }
 
   impl AnyPerson
@@ -264,14 +732,77 @@ mod v5
     {
       Self(Either::Right(Either::Right(person)))
     }
+
+    pub fn as_formal(&self) -> Option<&FormalPerson>
+    {
+      self.0.left_ref()
+    }
+
+    pub fn as_casual(&self) -> Option<&CasualPerson>
+    {
+      self.0.right_ref().and_then(Either::left_ref)
+    }
+
+    pub fn as_anon(&self) -> Option<&Anonymous>
+    {
+      self.0.right_ref().and_then(Either::right_ref)
+    }
+
+    pub fn into_formal(self) -> Result<FormalPerson, Self>
+    {
+      match self.0 {
+        Either::Left(person) => Ok(person),
+        other => Err(Self(other)),
+      }
+    }
+
+    pub fn into_casual(self) -> Result<CasualPerson, Self>
+    {
+      match self.0 {
+        Either::Right(Either::Left(person)) => Ok(person),
+        other => Err(Self(other)),
+      }
+    }
+
+    pub fn into_anon(self) -> Result<Anonymous, Self>
+    {
+      match self.0 {
+        Either::Right(Either::Right(person)) => Ok(person),
+        other => Err(Self(other)),
+      }
+    }
+  }
+
+  impl From<FormalPerson> for AnyPerson
+  {
+    fn from(person: FormalPerson) -> Self
+    {
+      Self::formal(person)
+    }
+  }
+
+  impl From<CasualPerson> for AnyPerson
+  {
+    fn from(person: CasualPerson) -> Self
+    {
+      Self::casual(person)
+    }
+  }
+
+  impl From<Anonymous> for AnyPerson
+  {
+    fn from(person: Anonymous) -> Self
+    {
+      Self::anon(person)
+    }
   }
 
   pub fn make_persons() -> Vec<AnyPerson>
   {
     vec![
-      AnyPerson::formal(FormalPerson::new("Mr.", "John", "Smith")),
-      AnyPerson::casual(CasualPerson::new("Alice")),
-      AnyPerson::anon(Anonymous::new(8)),
+      FormalPerson::new("Mr.", "John", "Smith").into(),
+      CasualPerson::new("Alice").into(),
+      Anonymous::new(8).into(),
     ]
   }
 
@@ -291,17 +822,262 @@ mod v5
       ]
     );
   }
-}
-
-mod v6
-{
-  use crate::v3::HasName;
 
-  pub trait Greeter
+  #[test]
+  fn test_map()
   {
-    fn greet(
-      &self,
-      person: &impl HasName,
+    let formal: AnyPersonGeneric =
+      Either::Left(FormalPerson::new("Mr.", "John", "Smith"));
+
+    match formal.map_left(|person| person.last_name) {
+      Either::Left(last_name) => assert_eq!(last_name, "Smith"),
+      Either::Right(_) => panic!("expected the left branch"),
+    }
+
+    let casual: AnyPersonGeneric =
+      Either::Right(Either::Left(CasualPerson::new("Alice")));
+
+    match casual.map_left(|person| person.last_name) {
+      Either::Right(rest) => assert_eq!(rest.name(), "Alice"),
+      Either::Left(_) => panic!("expected the right branch"),
+    }
+
+    let anon: AnyPersonGeneric =
+      Either::Right(Either::Right(Anonymous::new(8)));
+
+    match anon.map_left_ref(|person| person.last_name.clone()) {
+      Either::Right(rest) => assert_eq!(rest.name(), "Anonymous #8"),
+      Either::Left(_) => panic!("expected the right branch"),
+    }
+
+    let formal: AnyPersonGeneric =
+      Either::Left(FormalPerson::new("Mr.", "John", "Smith"));
+
+    let names = formal.bimap(|person| person.last_name, |rest| rest.name());
+
+    assert!(matches!(names, Either::Left(name) if name == "Smith"));
+  }
+
+  #[test]
+  fn test_swap()
+  {
+    let casual: Either<CasualPerson, Anonymous> =
+      Either::Left(CasualPerson::new("Alice"));
+
+    assert!(matches!(casual.swap_ref(), Either::Right(_)));
+
+    let swapped = casual.swap();
+
+    assert!(matches!(swapped, Either::Right(_)));
+    assert_eq!(swapped.name(), "Alice");
+
+    let twice = swapped.swap();
+
+    assert!(matches!(&twice, Either::Left(person) if person.name == "Alice"));
+
+    let anon: Either<CasualPerson, Anonymous> =
+      Either::Right(Anonymous::new(8));
+
+    assert!(
+      matches!(anon.swap().swap(), Either::Right(person) if person.id == 8)
+    );
+  }
+
+  #[test]
+  fn test_iterator()
+  {
+    fn names(
+      persons: &[CasualPerson],
+      reversed: bool,
+    ) -> impl Iterator<Item = String> + '_
+    {
+      if reversed {
+        Either::Left(persons.iter().rev().map(HasName::name))
+      } else {
+        Either::Right(persons.iter().map(HasName::name))
+      }
+    }
+
+    let persons = vec![CasualPerson::new("Alice"), CasualPerson::new("Bob")];
+
+    assert_eq!(
+      names(&persons, false).collect::<Vec<_>>(),
+      vec!["Alice", "Bob"]
+    );
+    assert_eq!(
+      names(&persons, true).collect::<Vec<_>>(),
+      vec!["Bob", "Alice"]
+    );
+
+    let left: Either<_, std::vec::IntoIter<u64>> =
+      Either::Left(vec![1, 2].into_iter());
+    let right: Either<std::vec::IntoIter<u64>, _> =
+      Either::Right(vec![3].into_iter());
+
+    assert_eq!(left.chain(right).collect::<Vec<_>>(), vec![1, 2, 3]);
+  }
+
+  #[test]
+  fn test_as_ref()
+  {
+    let mut person: AnyPersonGeneric =
+      Either::Right(Either::Left(CasualPerson::new("Alice")));
+
+    match person.as_ref() {
+      Either::Right(rest) => assert_eq!(rest.name(), "Alice"),
+      Either::Left(_) => panic!("expected the right branch"),
+    }
+
+    if let Either::Right(Either::Left(casual)) = person.as_mut() {
+      casual.name = "Alicia".to_string();
+    }
+
+    assert_eq!(person.name(), "Alicia");
+  }
+
+  #[test]
+  fn test_accessors()
+  {
+    let person = AnyPerson::casual(CasualPerson::new("Alice"));
+
+    assert!(person.0.is_right());
+    assert!(!person.0.is_left());
+    assert!(person.0.left_ref().is_none());
+    assert_eq!(
+      person
+        .0
+        .right_ref()
+        .and_then(|rest| rest.left_ref())
+        .map(|p| p.name()),
+      Some("Alice".to_string())
+    );
+
+    let rest = person.0.right().and_then(Either::left);
+
+    assert_eq!(rest.map(|p| p.name), Some("Alice".to_string()));
+
+    let anon = AnyPerson::anon(Anonymous::new(8));
+
+    assert!(anon.0.left().is_none());
+  }
+
+  #[test]
+  fn test_display()
+  {
+    let person = AnyPerson::anon(Anonymous::new(8));
+
+    assert_eq!(person.0.to_string(), Anonymous::new(8).to_string());
+    assert_eq!(person.0.to_string(), "Anonymous #8");
+
+    let formal = AnyPerson::formal(FormalPerson::new("Mr.", "John", "Smith"));
+
+    assert_eq!(format!("{}", formal.0), "Mr. John Smith");
+  }
+
+  #[test]
+  fn test_debug()
+  {
+    let formal = FormalPerson::new("Mr.", "John", "Smith");
+    let debug = format!("{:?}", formal);
+
+    assert!(debug.contains("Mr."));
+    assert!(debug.contains("John"));
+    assert!(debug.contains("Smith"));
+
+    let casual = AnyPerson::casual(CasualPerson::new("Alice"));
+
+    assert_eq!(
+      format!("{:?}", casual),
+      "Casual(CasualPerson { name: \"Alice\", age: None })"
+    );
+    assert_eq!(
+      format!("{:?}", casual.0),
+      "Right(Left(CasualPerson { name: \"Alice\", age: None }))"
+    );
+  }
+
+  #[test]
+  fn test_from()
+  {
+    let formal: AnyPerson = FormalPerson::new("Mr.", "John", "Smith").into();
+    let casual: AnyPerson = CasualPerson::new("Alice").into();
+    let anon: AnyPerson = Anonymous::new(8).into();
+
+    assert_eq!(
+      formal.name(),
+      AnyPerson::formal(FormalPerson::new("Mr.", "John", "Smith")).name()
+    );
+    assert_eq!(
+      casual.name(),
+      AnyPerson::casual(CasualPerson::new("Alice")).name()
+    );
+    assert_eq!(anon.name(), AnyPerson::anon(Anonymous::new(8)).name());
+
+    assert!(casual.0.right_ref().is_some_and(Either::is_left));
+  }
+
+  #[test]
+  fn test_extract()
+  {
+    let persons = make_persons();
+
+    assert_eq!(
+      persons[0].as_formal().map(|p| p.last_name.as_str()),
+      Some("Smith")
+    );
+    assert!(persons[0].as_casual().is_none());
+    assert_eq!(
+      persons[1].as_casual().map(|p| p.name.as_str()),
+      Some("Alice")
+    );
+    assert_eq!(persons[2].as_anon().map(|p| p.id), Some(8));
+
+    let anon = AnyPerson::anon(Anonymous::new(8));
+
+    let anon = match anon.into_casual() {
+      Ok(_) => panic!("expected an anonymous person"),
+      Err(original) => original,
+    };
+
+    assert_eq!(anon.name(), "Anonymous #8");
+    assert_eq!(anon.into_anon().map(|p| p.id).ok(), Some(8));
+  }
+
+  #[test]
+  fn test_greet_sorted()
+  {
+    use crate::v3::greet_sorted;
+
+    let persons: Vec<AnyPerson> = vec![
+      CasualPerson::new("Zoe").into(),
+      FormalPerson::new("Mr.", "John", "Smith").into(),
+      Anonymous::new(8).into(),
+      FormalPerson::new("Dr.", "Jane", "Doe").into(),
+      CasualPerson::new("Doe").into(),
+    ];
+
+    assert_eq!(
+      greet_sorted(&persons),
+      vec![
+        "Hello, Anonymous #8!",
+        "Hello, Dr. Jane Doe!",
+        "Hello, Doe!",
+        "Hello, Mr. John Smith!",
+        "Hello, Zoe!",
+      ]
+    );
+  }
+}
+
+mod v6
+{
+  use crate::v3::HasName;
+
+  pub trait Greeter
+  {
+    fn greet(
+      &self,
+      person: &impl HasName,
     ) -> String;
   }
 
@@ -318,6 +1094,7 @@ mod v6
     }
   }
 
+  #[derive(Clone)]
   pub struct WordGreeter
   {
     pub greet_word: String,
@@ -346,7 +1123,7 @@ mod v6
 
   pub fn greet_many<Greet: Greeter, Person: HasName>(
     greeter: &Greet,
-    persons: &Vec<Person>,
+    persons: &[Person],
   ) -> Vec<String>
   {
     persons.iter().map(|person| greeter.greet(person)).collect()
@@ -399,7 +1176,7 @@ mod v7
 
   fn greet_many<P, G: Greeter<P>>(
     greeter: &G,
-    persons: &Vec<P>,
+    persons: &[P],
   ) -> Vec<String>
   {
     persons.iter().map(|person| greeter.greet(person)).collect()
@@ -514,7 +1291,7 @@ mod v8
     ) -> String;
   }
 
-  pub struct Unit<G>(G);
+  pub struct Unit<G>(pub G);
 
   impl<G, A, B> Greeter<Either<A, B>> for Unit<G>
   where
@@ -558,7 +1335,8 @@ mod v8
     ) -> String;
   }
 
-  pub struct WithName<G>(G);
+  #[derive(Clone)]
+  pub struct WithName<G>(pub G);
 
   impl<G: NameGreeter, P: HasName> Greeter<P> for WithName<G>
   {
@@ -658,19 +1436,18 @@ mod v8
   }
 
   fn greet_many<P, G: Greeter<P>>(
-    greeters: &Vec<G>,
-    persons: &Vec<P>,
+    greeters: &[G],
+    persons: &[P],
   ) -> Vec<String>
   {
     greeters
       .iter()
-      .map(|greeter| {
+      .flat_map(|greeter| {
         persons
           .iter()
           .map(|person| greeter.greet(person))
           .collect::<Vec<_>>()
       })
-      .flatten()
       .collect()
   }
 
@@ -742,3 +1519,6114 @@ mod v8
     );
   }
 }
+
+mod batch
+{
+  use std::{
+    cmp::Reverse,
+    collections::HashMap,
+  };
+
+  use crate::{
+    v3::HasName,
+    v5::AnyPerson,
+    v8::Greeter,
+  };
+
+  pub fn greet_many_words<P: HasName>(
+    words: &[&str],
+    persons: &[P],
+  ) -> Vec<String>
+  {
+    persons
+      .iter()
+      .enumerate()
+      .map(|(i, person)| {
+        let word = if words.is_empty() {
+          "Hello"
+        } else {
+          words[i % words.len()]
+        };
+
+        format!("{}, {}!", word, person.name())
+      })
+      .collect()
+  }
+
+  pub fn greet_thunks<'a, P: HasName, G: Greeter<P>>(
+    greeter: &'a G,
+    persons: &'a [P],
+  ) -> Vec<impl Fn() -> String + 'a>
+  {
+    persons
+      .iter()
+      .map(move |person| move || greeter.greet(person))
+      .collect()
+  }
+
+  pub fn greet_any_iter<'a, I: IntoIterator<Item = &'a AnyPerson>>(
+    greeter: &'a impl Greeter<AnyPerson>,
+    persons: I,
+  ) -> impl Iterator<Item = String> + 'a
+  where
+    I::IntoIter: 'a,
+  {
+    persons.into_iter().map(move |person| greeter.greet(person))
+  }
+
+  pub fn greet_ranked<P: HasName>(
+    greeter: &impl Greeter<P>,
+    mut persons: Vec<P>,
+    score: impl Fn(&P) -> i64,
+  ) -> Vec<String>
+  {
+    persons.sort_by_key(|person| Reverse(score(person)));
+    persons.iter().map(|person| greeter.greet(person)).collect()
+  }
+
+  pub fn greet_many_bytes<P: HasName>(
+    greeter: &impl Greeter<P>,
+    persons: &[P],
+  ) -> Vec<Vec<u8>>
+  {
+    persons
+      .iter()
+      .map(|person| greeter.greet(person).into_bytes())
+      .collect()
+  }
+
+  struct XorShift64(u64);
+
+  impl XorShift64
+  {
+    fn new(seed: u64) -> Self
+    {
+      Self(if seed == 0 { 0x9e3779b97f4a7c15 } else { seed })
+    }
+
+    fn next(&mut self) -> u64
+    {
+      self.0 ^= self.0 << 13;
+      self.0 ^= self.0 >> 7;
+      self.0 ^= self.0 << 17;
+      self.0
+    }
+  }
+
+  pub fn greet_shuffled_seeded<P: HasName>(
+    greeter: &impl Greeter<P>,
+    persons: &[P],
+    seed: u64,
+  ) -> Vec<String>
+  {
+    let mut rng = XorShift64::new(seed);
+    let mut order: Vec<&P> = persons.iter().collect();
+
+    for i in (1..order.len()).rev() {
+      let j = (rng.next() % (i as u64 + 1)) as usize;
+      order.swap(i, j);
+    }
+
+    order.iter().map(|person| greeter.greet(*person)).collect()
+  }
+
+  #[cfg(feature = "rand")]
+  pub fn greet_sample<P: HasName>(
+    greeter: &impl Greeter<P>,
+    persons: &[P],
+    n: usize,
+    rng: &mut impl rand::Rng,
+  ) -> Vec<String>
+  {
+    use rand::seq::SliceRandom;
+
+    persons
+      .choose_multiple(rng, n)
+      .map(|person| greeter.greet(person))
+      .collect()
+  }
+
+  #[test]
+  fn test_greet_many_words()
+  {
+    use crate::v5::make_persons;
+
+    let persons = make_persons();
+
+    assert_eq!(
+      greet_many_words(&["Hi", "Hey"], &persons),
+      vec!["Hi, Mr. John Smith!", "Hey, Alice!", "Hi, Anonymous #8!"]
+    );
+
+    assert_eq!(
+      greet_many_words(&[], &persons[..1]),
+      vec!["Hello, Mr. John Smith!"]
+    );
+  }
+
+  #[test]
+  fn test_greet_any_iter()
+  {
+    use crate::{
+      v5::{
+        make_persons,
+        Either,
+      },
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    let persons = make_persons();
+    let greeter = AnyGreeter::polite(PoliteGreeter);
+
+    let formal = persons
+      .iter()
+      .filter(|person| matches!(person.0, Either::Left(_)));
+
+    assert_eq!(
+      greet_any_iter(&greeter, formal).collect::<Vec<_>>(),
+      vec!["Welcome back, Mr. Smith!"]
+    );
+  }
+
+  #[cfg(feature = "rand")]
+  #[test]
+  fn test_greet_sample()
+  {
+    use rand::{
+      rngs::StdRng,
+      SeedableRng,
+    };
+
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let persons: Vec<CasualPerson> = ["Alice", "Bob", "Carol", "Dave", "Eve"]
+      .iter()
+      .map(|name| CasualPerson::new(name))
+      .collect();
+
+    let greeter = WithName(WordGreeter::new("Hello"));
+
+    let first =
+      greet_sample(&greeter, &persons, 3, &mut StdRng::seed_from_u64(7));
+    let second =
+      greet_sample(&greeter, &persons, 3, &mut StdRng::seed_from_u64(7));
+
+    assert_eq!(first.len(), 3);
+    assert_eq!(first, second);
+
+    let mut all =
+      greet_sample(&greeter, &persons, 10, &mut StdRng::seed_from_u64(7));
+    all.sort();
+
+    assert_eq!(
+      all,
+      vec![
+        "Hello, Alice!",
+        "Hello, Bob!",
+        "Hello, Carol!",
+        "Hello, Dave!",
+        "Hello, Eve!",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_greet_thunks()
+  {
+    use crate::{
+      decorators::CountingGreeter,
+      v5::make_persons,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let persons = make_persons();
+    let greeter = CountingGreeter::new(WithName(WordGreeter::new("Hello")));
+
+    let thunks = greet_thunks(&greeter, &persons);
+
+    assert_eq!(greeter.count(), 0);
+    assert_eq!(thunks[1](), "Hello, Alice!");
+    assert_eq!(greeter.count(), 1);
+  }
+
+  #[test]
+  fn test_greet_ranked()
+  {
+    use crate::{
+      kind::PersonKind,
+      v3::CasualPerson,
+      v5::make_persons,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let mut persons = make_persons();
+    persons.insert(0, AnyPerson::casual(CasualPerson::new("Bob")));
+    persons.reverse();
+
+    let greetings =
+      greet_ranked(&WithName(WordGreeter::new("Hi")), persons, |person| {
+        match person.kind() {
+          PersonKind::Formal => 10,
+          PersonKind::Casual => 5,
+          PersonKind::Anon => 0,
+        }
+      });
+
+    assert_eq!(
+      greetings,
+      vec![
+        "Hi, Mr. John Smith!",
+        "Hi, Alice!",
+        "Hi, Bob!",
+        "Hi, Anonymous #8!",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_greet_many_bytes()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let persons = [CasualPerson::new("Alice"), CasualPerson::new("Zoë")];
+
+    let decoded: Vec<String> =
+      greet_many_bytes(&WithName(WordGreeter::new("Hi")), &persons)
+        .into_iter()
+        .map(|bytes| String::from_utf8(bytes).unwrap())
+        .collect();
+
+    assert_eq!(decoded, vec!["Hi, Alice!", "Hi, Zoë!"]);
+  }
+
+  #[test]
+  fn test_greet_shuffled_seeded()
+  {
+    use crate::{
+      v3::Anonymous,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let persons: Vec<Anonymous> = (0..10).map(Anonymous::new).collect();
+    let greeter = WithName(WordGreeter::new("Hi"));
+
+    let first = greet_shuffled_seeded(&greeter, &persons, 42);
+    let second = greet_shuffled_seeded(&greeter, &persons, 42);
+    let other = greet_shuffled_seeded(&greeter, &persons, 7);
+
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+
+    let mut sorted = first.clone();
+    sorted.sort();
+    let mut expected = greet_many_words(&["Hi"], &persons);
+    expected.sort();
+
+    assert_eq!(sorted, expected);
+  }
+
+  pub fn greet_with_counts<P: HasName>(
+    greeter: &impl Greeter<P>,
+    persons: &[P],
+  ) -> Vec<String>
+  {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+
+    for person in persons {
+      let greeting = greeter.greet(person);
+
+      match positions.get(&greeting) {
+        Some(&i) => counts[i].1 += 1,
+        None => {
+          positions.insert(greeting.clone(), counts.len());
+          counts.push((greeting, 1));
+        }
+      }
+    }
+
+    counts
+      .into_iter()
+      .map(|(greeting, count)| {
+        if count == 1 {
+          greeting
+        } else {
+          format!("{} (x{})", greeting, count)
+        }
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_greet_with_counts()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let persons = [
+      CasualPerson::new("Alice"),
+      CasualPerson::new("Bob"),
+      CasualPerson::new("Alice"),
+      CasualPerson::new("Alice"),
+    ];
+
+    assert_eq!(
+      greet_with_counts(&WithName(WordGreeter::new("Hello")), &persons),
+      vec!["Hello, Alice! (x3)", "Hello, Bob!"]
+    );
+  }
+}
+
+mod builder
+{
+  use crate::{
+    v3::{
+      Anonymous,
+      CasualPerson,
+      FormalPerson,
+    },
+    v5::AnyPerson,
+  };
+
+  #[derive(Debug, PartialEq)]
+  pub enum PersonBuildError
+  {
+    Empty,
+    Ambiguous,
+  }
+
+  #[derive(Default)]
+  pub struct PersonBuilder
+  {
+    title: Option<String>,
+    first: Option<String>,
+    last: Option<String>,
+    name: Option<String>,
+    id: Option<u64>,
+  }
+
+  impl PersonBuilder
+  {
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    pub fn title(
+      mut self,
+      title: &str,
+    ) -> Self
+    {
+      self.title = Some(title.to_string());
+      self
+    }
+
+    pub fn first(
+      mut self,
+      first: &str,
+    ) -> Self
+    {
+      self.first = Some(first.to_string());
+      self
+    }
+
+    pub fn last(
+      mut self,
+      last: &str,
+    ) -> Self
+    {
+      self.last = Some(last.to_string());
+      self
+    }
+
+    pub fn name(
+      mut self,
+      name: &str,
+    ) -> Self
+    {
+      self.name = Some(name.to_string());
+      self
+    }
+
+    pub fn id(
+      mut self,
+      id: u64,
+    ) -> Self
+    {
+      self.id = Some(id);
+      self
+    }
+
+    pub fn build(self) -> Result<AnyPerson, PersonBuildError>
+    {
+      match (self.title, self.first, self.last, self.name, self.id) {
+        (Some(title), Some(first), Some(last), None, None) => {
+          Ok(AnyPerson::formal(FormalPerson::new(&title, &first, &last)))
+        }
+        (None, None, None, Some(name), None) => {
+          Ok(AnyPerson::casual(CasualPerson::new(&name)))
+        }
+        (None, None, None, None, Some(id)) => {
+          Ok(AnyPerson::anon(Anonymous::new(id)))
+        }
+        (None, None, None, None, None) => Err(PersonBuildError::Empty),
+        _ => Err(PersonBuildError::Ambiguous),
+      }
+    }
+  }
+
+  pub const KNOWN_TITLES: &[&str] =
+    &["Mr.", "Mrs.", "Ms.", "Mx.", "Dr.", "Prof."];
+
+  pub fn parse_formal_name(raw: &str) -> AnyPerson
+  {
+    let tokens: Vec<&str> = raw.split_whitespace().collect();
+
+    match tokens.as_slice() {
+      [title, first, rest @ ..]
+        if !rest.is_empty() && KNOWN_TITLES.contains(title) =>
+      {
+        AnyPerson::formal(FormalPerson::new(title, first, &rest.join(" ")))
+      }
+      _ => AnyPerson::casual(CasualPerson::new(&tokens.join(" "))),
+    }
+  }
+
+  #[test]
+  fn test()
+  {
+    use crate::{
+      v3::HasName,
+      v5::Either,
+    };
+
+    let formal = PersonBuilder::new()
+      .title("Mr.")
+      .first("John")
+      .last("Smith")
+      .build()
+      .ok()
+      .unwrap();
+
+    assert!(matches!(formal.0, Either::Left(_)));
+    assert_eq!(formal.name(), "Mr. John Smith");
+
+    let casual = PersonBuilder::new().name("Alice").build().ok().unwrap();
+
+    assert!(matches!(casual.0, Either::Right(Either::Left(_))));
+    assert_eq!(casual.name(), "Alice");
+
+    let anon = PersonBuilder::new().id(8).build().ok().unwrap();
+
+    assert!(matches!(anon.0, Either::Right(Either::Right(_))));
+    assert_eq!(anon.name(), "Anonymous #8");
+
+    assert_eq!(
+      PersonBuilder::new().name("Alice").id(8).build().err(),
+      Some(PersonBuildError::Ambiguous)
+    );
+
+    assert_eq!(
+      PersonBuilder::new().build().err(),
+      Some(PersonBuildError::Empty)
+    );
+  }
+
+  #[test]
+  fn test_parse_formal_name()
+  {
+    use crate::{
+      v3::HasName,
+      v5::Either,
+    };
+
+    let formal = parse_formal_name("Dr. Jane Doe");
+
+    match &formal.0 {
+      Either::Left(person) => {
+        assert_eq!(person.title, "Dr.");
+        assert_eq!(person.first_name, "Jane");
+        assert_eq!(person.last_name, "Doe");
+      }
+      _ => panic!("expected a formal person"),
+    }
+
+    let casual = parse_formal_name("Jane Doe");
+
+    assert!(matches!(casual.0, Either::Right(Either::Left(_))));
+    assert_eq!(casual.name(), "Jane Doe");
+
+    let single = parse_formal_name("Cher");
+
+    assert!(matches!(single.0, Either::Right(Either::Left(_))));
+    assert_eq!(single.name(), "Cher");
+  }
+
+  fn edit_distance(
+    a: &str,
+    b: &str,
+  ) -> usize
+  {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+      let mut diagonal = row[0];
+      row[0] = i + 1;
+
+      for (j, cb) in b.iter().enumerate() {
+        let substitute = diagonal + if ca == *cb { 0 } else { 1 };
+        diagonal = row[j + 1];
+        row[j + 1] = substitute.min(row[j] + 1).min(diagonal + 1);
+      }
+    }
+
+    row[b.len()]
+  }
+
+  pub fn name_similarity(
+    a: &str,
+    b: &str,
+  ) -> f64
+  {
+    let a = a.to_lowercase();
+    let b = b.to_lowercase();
+    let longest = a.chars().count().max(b.chars().count());
+
+    if longest == 0 {
+      return 1.0;
+    }
+
+    1.0 - edit_distance(&a, &b) as f64 / longest as f64
+  }
+
+  const TITLE_SIMILARITY_THRESHOLD: f64 = 0.6;
+
+  pub fn correct_title(raw: &str) -> String
+  {
+    let best = KNOWN_TITLES
+      .iter()
+      .map(|title| (title, name_similarity(raw, title)))
+      .fold(None, |best: Option<(&&str, f64)>, candidate| match best {
+        Some(best) if best.1 >= candidate.1 => Some(best),
+        _ => Some(candidate),
+      });
+
+    match best {
+      Some((title, score)) if score >= TITLE_SIMILARITY_THRESHOLD => {
+        title.to_string()
+      }
+      _ => raw.to_string(),
+    }
+  }
+
+  impl FormalPerson
+  {
+    pub fn new_corrected(
+      title: &str,
+      first_name: &str,
+      last_name: &str,
+    ) -> Self
+    {
+      FormalPerson::new(&correct_title(title), first_name, last_name)
+    }
+  }
+
+  #[test]
+  fn test_correct_title()
+  {
+    assert_eq!(correct_title("Mrr."), "Mr.");
+    assert_eq!(correct_title("Dr"), "Dr.");
+    assert_eq!(correct_title("prof."), "Prof.");
+    assert_eq!(correct_title("Mr."), "Mr.");
+    assert_eq!(correct_title("Captain"), "Captain");
+
+    let person = FormalPerson::new_corrected("Mrs", "Jane", "Doe");
+    assert_eq!(person.title, "Mrs.");
+  }
+}
+
+mod decorators
+{
+  use std::cell::Cell;
+
+  use crate::{
+    v3::HasName,
+    v8::Greeter,
+  };
+
+  #[derive(Clone, Copy)]
+  pub enum Season
+  {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+  }
+
+  impl Season
+  {
+    pub fn emoji(&self) -> &'static str
+    {
+      match self {
+        Self::Spring => "🌷",
+        Self::Summer => "🌞",
+        Self::Autumn => "🍂",
+        Self::Winter => "🎄",
+      }
+    }
+  }
+
+  pub struct SeasonalGreeter<G>
+  {
+    pub season: Season,
+    pub inner: G,
+  }
+
+  impl<P, G: Greeter<P>> Greeter<P> for SeasonalGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      format!("{} {}", self.season.emoji(), self.inner.greet(person))
+    }
+  }
+
+  pub struct CountingGreeter<G>
+  {
+    pub inner: G,
+    count: Cell<usize>,
+  }
+
+  impl<G> CountingGreeter<G>
+  {
+    pub fn new(inner: G) -> Self
+    {
+      Self {
+        inner,
+        count: Cell::new(0),
+      }
+    }
+
+    pub fn count(&self) -> usize
+    {
+      self.count.get()
+    }
+  }
+
+  impl<P, G: Greeter<P>> Greeter<P> for CountingGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      self.count.set(self.count.get() + 1);
+      self.inner.greet(person)
+    }
+  }
+
+  pub struct BracketedGreeter<G>
+  {
+    pub opener: String,
+    pub closer: String,
+    pub inner: G,
+  }
+
+  impl<P, G: Greeter<P>> Greeter<P> for BracketedGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      format!(
+        "{} {} {}",
+        self.opener,
+        self.inner.greet(person),
+        self.closer
+      )
+    }
+  }
+
+  pub struct PromptGreeter<G>
+  {
+    pub inner: G,
+    pub prompt: String,
+  }
+
+  impl<P: HasName, G: Greeter<P>> Greeter<P> for PromptGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      format!(
+        "{} {}",
+        self.inner.greet(person),
+        self.prompt.replace("{name}", &person.name())
+      )
+    }
+  }
+
+  #[derive(Clone, Copy)]
+  pub enum BracketStyle
+  {
+    Square,
+    Round,
+    Angle,
+  }
+
+  impl BracketStyle
+  {
+    pub fn wrap(
+      &self,
+      tag: &str,
+    ) -> String
+    {
+      match self {
+        Self::Square => format!("[{}]", tag),
+        Self::Round => format!("({})", tag),
+        Self::Angle => format!("<{}>", tag),
+      }
+    }
+  }
+
+  pub struct TenantGreeter<G>
+  {
+    pub tenant: String,
+    pub style: BracketStyle,
+    pub inner: G,
+  }
+
+  impl<P, G: Greeter<P>> Greeter<P> for TenantGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      format!(
+        "{} {}",
+        self.style.wrap(&self.tenant),
+        self.inner.greet(person)
+      )
+    }
+  }
+
+  pub struct MaxLinesGreeter<G>
+  {
+    pub inner: G,
+    pub max_lines: usize,
+  }
+
+  impl<P, G: Greeter<P>> Greeter<P> for MaxLinesGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let greeting = self.inner.greet(person);
+      let lines: Vec<&str> = greeting.lines().collect();
+
+      if lines.len() <= self.max_lines {
+        greeting
+      } else {
+        format!("{}…", lines[..self.max_lines].join("\n"))
+      }
+    }
+  }
+
+  #[test]
+  fn test_seasonal_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let person = CasualPerson::new("Alice");
+
+    let winter = SeasonalGreeter {
+      season: Season::Winter,
+      inner: WithName(WordGreeter::new("Merry greetings")),
+    };
+
+    assert_eq!(winter.greet(&person), "🎄 Merry greetings, Alice!");
+
+    let summer = SeasonalGreeter {
+      season: Season::Summer,
+      inner: WithName(WordGreeter::new("Hello")),
+    };
+
+    assert_eq!(summer.greet(&person), "🌞 Hello, Alice!");
+  }
+
+  #[test]
+  fn test_bracketed_greeter()
+  {
+    use crate::{
+      v3::FormalPerson,
+      v8::{
+        PoliteGreeter,
+        Unit,
+      },
+    };
+
+    let greeter = BracketedGreeter {
+      opener: "Dear Mr. Smith,".to_string(),
+      closer: "Sincerely.".to_string(),
+      inner: Unit(PoliteGreeter),
+    };
+
+    assert_eq!(
+      greeter.greet(&FormalPerson::new("Mr.", "John", "Smith")),
+      "Dear Mr. Smith, Welcome back, Mr. Smith! Sincerely."
+    );
+  }
+
+  #[test]
+  fn test_prompt_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = PromptGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+      prompt: "How can I help you today, {name}?".to_string(),
+    };
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Alice")),
+      "Hello, Alice! How can I help you today, Alice?"
+    );
+  }
+
+  #[test]
+  fn test_tenant_greeter()
+  {
+    use crate::{
+      v5::make_persons,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = TenantGreeter {
+      tenant: "Acme".to_string(),
+      style: BracketStyle::Square,
+      inner: WithName(WordGreeter::new("Hello")),
+    };
+
+    let greetings: Vec<String> = make_persons()
+      .iter()
+      .map(|person| greeter.greet(person))
+      .collect();
+
+    assert_eq!(
+      greetings,
+      vec![
+        "[Acme] Hello, Mr. John Smith!",
+        "[Acme] Hello, Alice!",
+        "[Acme] Hello, Anonymous #8!",
+      ]
+    );
+
+    let greeter = TenantGreeter {
+      style: BracketStyle::Angle,
+      ..greeter
+    };
+
+    assert_eq!(greeter.greet(&make_persons()[1]), "<Acme> Hello, Alice!");
+  }
+
+  #[test]
+  fn test_max_lines_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let person = CasualPerson::new("Alice");
+
+    let greeter = MaxLinesGreeter {
+      inner: BracketedGreeter {
+        opener: "Dear Alice,\n".to_string(),
+        closer: "\nSincerely,\nBob".to_string(),
+        inner: WithName(WordGreeter::new("Hello")),
+      },
+      max_lines: 4,
+    };
+
+    assert_eq!(
+      greeter.greet(&person),
+      "Dear Alice,\n Hello, Alice! \nSincerely,\nBob"
+    );
+
+    let greeter = MaxLinesGreeter {
+      max_lines: 2,
+      ..greeter
+    };
+
+    assert_eq!(greeter.greet(&person), "Dear Alice,\n Hello, Alice! …");
+  }
+
+  pub struct SelfIntroGreeter<G, Me>
+  {
+    pub inner: G,
+    pub me: Me,
+  }
+
+  impl<P, G, Me> Greeter<P> for SelfIntroGreeter<G, Me>
+  where
+    G: Greeter<P>,
+    Me: HasName,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      format!("{} — {}", self.inner.greet(person), self.me.name())
+    }
+  }
+
+  #[test]
+  fn test_self_intro_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v5::make_persons,
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    let greeter = SelfIntroGreeter {
+      inner: AnyGreeter::polite(PoliteGreeter),
+      me: CasualPerson::new("Bob"),
+    };
+
+    let greetings: Vec<String> = make_persons()
+      .iter()
+      .map(|person| greeter.greet(person))
+      .collect();
+
+    assert_eq!(
+      greetings,
+      vec![
+        "Welcome back, Mr. Smith! — Bob",
+        "Hello, Alice! — Bob",
+        "Hello stranger, your ID is 8. — Bob",
+      ]
+    );
+  }
+
+  // Each '\r' and '\n' becomes a single space, so a "\r\n" pair turns into
+  // two spaces. Spaces keep the greeting readable in logs, where an escaped
+  // "\\n" would be mistaken for literal text.
+  pub struct SanitizeGreeter<G>
+  {
+    pub inner: G,
+  }
+
+  impl<P, G: Greeter<P>> Greeter<P> for SanitizeGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      self.inner.greet(person).replace(['\r', '\n'], " ")
+    }
+  }
+
+  #[test]
+  fn test_sanitize_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = SanitizeGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+    };
+
+    let greeting = greeter.greet(&CasualPerson::new("Alice\nEvil"));
+
+    assert!(!greeting.contains(['\r', '\n']));
+    assert_eq!(greeting, "Hello, Alice Evil!");
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Alice\r\nEvil")),
+      "Hello, Alice  Evil!"
+    );
+  }
+
+  pub struct AndThen<G1, G2>(pub G1, pub G2);
+
+  impl<P, G1, G2> Greeter<P> for AndThen<G1, G2>
+  where
+    G1: Greeter<P>,
+    G2: Greeter<P>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let first = self.0.greet(person);
+      let second = self.1.greet(person);
+
+      if first.is_empty() || second.is_empty() {
+        first + &second
+      } else {
+        format!("{} {}", first, second)
+      }
+    }
+  }
+
+  #[test]
+  fn test_and_then()
+  {
+    use crate::{
+      v5::make_persons,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    struct HowAreYou;
+
+    impl<P> Greeter<P> for HowAreYou
+    {
+      fn greet(
+        &self,
+        _person: &P,
+      ) -> String
+      {
+        "How are you?".to_string()
+      }
+    }
+
+    let greeter = AndThen(WithName(WordGreeter::new("Hi")), HowAreYou);
+
+    let greetings: Vec<String> = make_persons()
+      .iter()
+      .map(|person| greeter.greet(person))
+      .collect();
+
+    assert_eq!(
+      greetings,
+      vec![
+        "Hi, Mr. John Smith! How are you?",
+        "Hi, Alice! How are you?",
+        "Hi, Anonymous #8! How are you?",
+      ]
+    );
+
+    let greeter = AndThen(greeter, HowAreYou);
+
+    assert_eq!(
+      greeter.greet(&make_persons()[1]),
+      "Hi, Alice! How are you? How are you?"
+    );
+
+    struct Silent;
+
+    impl<P> Greeter<P> for Silent
+    {
+      fn greet(
+        &self,
+        _person: &P,
+      ) -> String
+      {
+        String::new()
+      }
+    }
+
+    let person = &make_persons()[1];
+
+    assert_eq!(AndThen(Silent, HowAreYou).greet(person), "How are you?");
+    assert_eq!(AndThen(HowAreYou, Silent).greet(person), "How are you?");
+    assert_eq!(AndThen(Silent, Silent).greet(person), "");
+  }
+
+  pub struct Renamed<'a, P>
+  {
+    pub person: &'a P,
+    pub name: String,
+  }
+
+  impl<'a, P> Renamed<'a, P>
+  {
+    pub fn new(
+      person: &'a P,
+      name: impl Into<String>,
+    ) -> Self
+    {
+      Self {
+        person,
+        name: name.into(),
+      }
+    }
+  }
+
+  impl<'a, P> HasName for Renamed<'a, P>
+  {
+    fn name(&self) -> String
+    {
+      self.name.clone()
+    }
+  }
+}
+
+mod std_impls
+{
+  use std::{
+    borrow::Cow,
+    cell::RefCell,
+    convert::Infallible,
+    pin::Pin,
+    rc::Weak,
+    sync::atomic::{
+      AtomicU64,
+      Ordering,
+    },
+  };
+
+  use crate::{
+    v3::HasName,
+    v8::Greeter,
+  };
+
+  impl<T: HasName> HasName for Weak<T>
+  {
+    fn name(&self) -> String
+    {
+      match self.upgrade() {
+        Some(person) => person.name(),
+        None => "Anonymous".to_string(),
+      }
+    }
+  }
+
+  #[test]
+  fn test_weak()
+  {
+    use std::rc::Rc;
+
+    use crate::v3::CasualPerson;
+
+    let person = Rc::new(CasualPerson::new("Alice"));
+    let weak = Rc::downgrade(&person);
+
+    assert_eq!(weak.name(), "Alice");
+
+    drop(person);
+
+    assert_eq!(weak.name(), "Anonymous");
+  }
+
+  impl HasName for Infallible
+  {
+    fn name(&self) -> String
+    {
+      unreachable!()
+    }
+  }
+
+  #[test]
+  fn test_infallible()
+  {
+    use crate::{
+      v3::{
+        greet_many_generic,
+        FormalPerson,
+      },
+      v5::Either,
+    };
+
+    let persons: Vec<Either<FormalPerson, Infallible>> =
+      vec![Either::Left(FormalPerson::new("Mr.", "John", "Smith"))];
+
+    assert_eq!(greet_many_generic(&persons), vec!["Hello, Mr. John Smith!"]);
+  }
+
+  impl<T: HasName> HasName for Pin<&T>
+  {
+    fn name(&self) -> String
+    {
+      self.get_ref().name()
+    }
+  }
+
+  #[test]
+  fn test_pin()
+  {
+    use crate::v3::{
+      greet_many_generic,
+      CasualPerson,
+    };
+
+    let person = CasualPerson::new("Alice");
+
+    assert_eq!(
+      greet_many_generic(&[Pin::new(&person)]),
+      vec!["Hello, Alice!"]
+    );
+  }
+
+  impl<P, G: Greeter<P> + ToOwned + ?Sized> Greeter<P> for Cow<'_, G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      self.as_ref().greet(person)
+    }
+  }
+
+  #[test]
+  fn test_cow_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let person = CasualPerson::new("Alice");
+    let greeter = WithName(WordGreeter::new("Hello"));
+
+    let borrowed = Cow::Borrowed(&greeter);
+    let owned: Cow<WithName<WordGreeter>> =
+      Cow::Owned(WithName(WordGreeter::new("Howdy")));
+
+    assert_eq!(borrowed.greet(&person), "Hello, Alice!");
+    assert_eq!(owned.greet(&person), "Howdy, Alice!");
+  }
+
+  // Only a shared borrow is taken, so this panics only if the greeter is
+  // mutably borrowed elsewhere while a greeting is in progress.
+  impl<P, G: Greeter<P>> Greeter<P> for RefCell<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      self.borrow().greet(person)
+    }
+  }
+
+  #[test]
+  fn test_refcell_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let person = CasualPerson::new("Alice");
+    let greeter = RefCell::new(WithName(WordGreeter::new("Hello")));
+
+    assert_eq!(greeter.greet(&person), "Hello, Alice!");
+
+    greeter.borrow_mut().0.greet_word = "Howdy".to_string();
+
+    assert_eq!(greeter.greet(&person), "Howdy, Alice!");
+  }
+
+  // A relaxed load is enough here: the id is only displayed and no other
+  // memory is synchronized through it.
+  impl HasName for AtomicU64
+  {
+    fn name(&self) -> String
+    {
+      format!("Anonymous #{}", self.load(Ordering::Relaxed))
+    }
+  }
+
+  #[test]
+  fn test_atomic_u64()
+  {
+    use crate::v3::greet_many_generic;
+
+    let counter = AtomicU64::new(0);
+    counter.store(42, Ordering::Relaxed);
+
+    assert_eq!(
+      greet_many_generic(&[counter]),
+      vec!["Hello, Anonymous #42!"]
+    );
+  }
+
+  impl HasName for &str
+  {
+    fn name(&self) -> String
+    {
+      self.to_string()
+    }
+  }
+
+  impl HasName for String
+  {
+    fn name(&self) -> String
+    {
+      self.clone()
+    }
+  }
+
+  #[test]
+  fn test_str()
+  {
+    use crate::v3::greet_many_generic;
+
+    assert_eq!(
+      greet_many_generic(&["Alice", "Bob"]),
+      vec!["Hello, Alice!", "Hello, Bob!"]
+    );
+
+    assert_eq!(
+      greet_many_generic(&["Carol".to_string(), String::new()]),
+      vec!["Hello, Carol!", "Hello, !"]
+    );
+  }
+
+  impl<P: HasName> HasName for Option<P>
+  {
+    fn name(&self) -> String
+    {
+      match self {
+        Some(person) => person.name(),
+        None => "Guest".to_string(),
+      }
+    }
+  }
+
+  #[derive(Clone, PartialEq, Eq, Hash)]
+  pub struct HasNameOr<P>
+  {
+    pub person: Option<P>,
+    pub fallback: String,
+  }
+
+  impl<P> HasNameOr<P>
+  {
+    pub fn new(
+      person: Option<P>,
+      fallback: &str,
+    ) -> Self
+    {
+      Self {
+        person,
+        fallback: fallback.to_string(),
+      }
+    }
+  }
+
+  impl<P: HasName> HasName for HasNameOr<P>
+  {
+    fn name(&self) -> String
+    {
+      match &self.person {
+        Some(person) => person.name(),
+        None => self.fallback.clone(),
+      }
+    }
+  }
+
+  #[test]
+  fn test_option()
+  {
+    use crate::v3::{
+      greet_many_generic,
+      CasualPerson,
+    };
+
+    assert_eq!(
+      greet_many_generic(&[Some(CasualPerson::new("Alice")), None]),
+      vec!["Hello, Alice!", "Hello, Guest!"]
+    );
+
+    assert_eq!(
+      greet_many_generic(&[
+        HasNameOr::new(Some(CasualPerson::new("Alice")), "Visitor"),
+        HasNameOr::new(None, "Visitor"),
+      ]),
+      vec!["Hello, Alice!", "Hello, Visitor!"]
+    );
+  }
+}
+
+mod fallible
+{
+  use std::convert::Infallible;
+
+  use crate::{
+    v3::HasName,
+    v7,
+    v8::Greeter,
+  };
+
+  #[derive(Debug, PartialEq)]
+  #[rustfmt::skip]
+  pub enum GreetError
+  {
+    TooLong
+    {
+      len: usize, max: usize
+    },
+    TooManyPersons
+    {
+      len: usize, max: usize
+    },
+    EmptyName,
+  }
+
+  pub struct BoundedGreeter<G>
+  {
+    pub inner: G,
+    pub max_bytes: usize,
+  }
+
+  pub trait FallibleGreeter<Person>
+  {
+    fn try_greet(
+      &self,
+      person: &Person,
+    ) -> Result<String, GreetError>;
+  }
+
+  impl<P, G: Greeter<P>> FallibleGreeter<P> for BoundedGreeter<G>
+  {
+    fn try_greet(
+      &self,
+      person: &P,
+    ) -> Result<String, GreetError>
+    {
+      let greeting = self.inner.greet(person);
+
+      if greeting.len() > self.max_bytes {
+        Err(GreetError::TooLong {
+          len: greeting.len(),
+          max: self.max_bytes,
+        })
+      } else {
+        Ok(greeting)
+      }
+    }
+  }
+
+  pub struct NonEmptyGreeter<G>
+  {
+    pub inner: G,
+  }
+
+  impl<P: HasName, G: Greeter<P>> FallibleGreeter<P> for NonEmptyGreeter<G>
+  {
+    fn try_greet(
+      &self,
+      person: &P,
+    ) -> Result<String, GreetError>
+    {
+      if person.name().trim().is_empty() {
+        Err(GreetError::EmptyName)
+      } else {
+        Ok(self.inner.greet(person))
+      }
+    }
+  }
+
+  pub fn greet_try_iter<'a, P, G: FallibleGreeter<P>>(
+    greeter: &'a G,
+    persons: &'a [P],
+  ) -> impl Iterator<Item = Result<String, GreetError>> + 'a
+  {
+    persons.iter().map(move |person| greeter.try_greet(person))
+  }
+
+  pub fn greet_bounded<P: HasName>(
+    greeter: &impl Greeter<P>,
+    persons: &[P],
+    max: usize,
+  ) -> Result<Vec<String>, GreetError>
+  {
+    if persons.len() > max {
+      return Err(GreetError::TooManyPersons {
+        len: persons.len(),
+        max,
+      });
+    }
+
+    Ok(persons.iter().map(|person| greeter.greet(person)).collect())
+  }
+
+  #[test]
+  fn test_bounded_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let person = CasualPerson::new("Zoë");
+
+    let greeter = BoundedGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+      max_bytes: 12,
+    };
+
+    assert_eq!(
+      FallibleGreeter::try_greet(&greeter, &person),
+      Ok("Hello, Zoë!".to_string())
+    );
+
+    let greeter = BoundedGreeter {
+      max_bytes: 11,
+      ..greeter
+    };
+
+    assert_eq!(
+      FallibleGreeter::try_greet(&greeter, &person),
+      Err(GreetError::TooLong { len: 12, max: 11 })
+    );
+  }
+
+  #[test]
+  fn test_greet_bounded()
+  {
+    use crate::{
+      v5::make_persons,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let persons = make_persons();
+    let greeter = WithName(WordGreeter::new("Hi"));
+
+    assert_eq!(
+      greet_bounded(&greeter, &persons, 3),
+      Ok(vec![
+        "Hi, Mr. John Smith!".to_string(),
+        "Hi, Alice!".to_string(),
+        "Hi, Anonymous #8!".to_string(),
+      ])
+    );
+
+    assert_eq!(
+      greet_bounded(&greeter, &persons, 2),
+      Err(GreetError::TooManyPersons { len: 3, max: 2 })
+    );
+  }
+
+  #[test]
+  fn test_greet_try_iter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let persons = [
+      CasualPerson::new("Alice"),
+      CasualPerson::new(""),
+      CasualPerson::new("Bob"),
+    ];
+
+    let greeter = NonEmptyGreeter {
+      inner: WithName(WordGreeter::new("Hi")),
+    };
+
+    assert_eq!(
+      greet_try_iter(&greeter, &persons).collect::<Vec<_>>(),
+      vec![
+        Ok("Hi, Alice!".to_string()),
+        Err(GreetError::EmptyName),
+        Ok("Hi, Bob!".to_string()),
+      ]
+    );
+
+    let greeter = BoundedGreeter {
+      inner: WithName(WordGreeter::new("Hi")),
+      max_bytes: 9,
+    };
+
+    assert_eq!(
+      greet_try_iter(&greeter, &persons).collect::<Vec<_>>(),
+      vec![
+        Err(GreetError::TooLong { len: 10, max: 9 }),
+        Ok("Hi, !".to_string()),
+        Ok("Hi, Bob!".to_string()),
+      ]
+    );
+  }
+
+  pub trait TryGreeter<Person>
+  {
+    type Error;
+
+    fn try_greet(
+      &self,
+      person: &Person,
+    ) -> Result<String, Self::Error>;
+  }
+
+  impl<P, G: v7::Greeter<P>> TryGreeter<P> for G
+  {
+    type Error = Infallible;
+
+    fn try_greet(
+      &self,
+      person: &P,
+    ) -> Result<String, Infallible>
+    {
+      Ok(self.greet(person))
+    }
+  }
+
+  pub fn try_greet_many<P, G: TryGreeter<P>>(
+    greeter: &G,
+    persons: &[P],
+  ) -> Result<Vec<String>, G::Error>
+  {
+    persons
+      .iter()
+      .map(|person| greeter.try_greet(person))
+      .collect()
+  }
+
+  #[test]
+  fn test_try_greet_many()
+  {
+    use crate::v5::{
+      make_persons,
+      AnyPerson,
+    };
+
+    struct NameGreeter;
+
+    impl v7::Greeter<AnyPerson> for NameGreeter
+    {
+      fn greet(
+        &self,
+        person: &AnyPerson,
+      ) -> String
+      {
+        format!("Hello, {}!", person.name())
+      }
+    }
+
+    struct NoAnonymousGreeter;
+
+    impl TryGreeter<AnyPerson> for NoAnonymousGreeter
+    {
+      type Error = String;
+
+      fn try_greet(
+        &self,
+        person: &AnyPerson,
+      ) -> Result<String, String>
+      {
+        match person.as_anon() {
+          Some(anon) => Err(format!("cannot greet anonymous #{}", anon.id)),
+          None => Ok(format!("Hello, {}!", person.name())),
+        }
+      }
+    }
+
+    let persons = make_persons();
+
+    assert_eq!(
+      try_greet_many(&NameGreeter, &persons),
+      Ok(vec![
+        "Hello, Mr. John Smith!".to_string(),
+        "Hello, Alice!".to_string(),
+        "Hello, Anonymous #8!".to_string(),
+      ])
+    );
+
+    assert_eq!(
+      try_greet_many(&NoAnonymousGreeter, &persons),
+      Err("cannot greet anonymous #8".to_string())
+    );
+    assert_eq!(
+      try_greet_many(&NoAnonymousGreeter, &persons[..2]).map(|g| g.len()),
+      Ok(2)
+    );
+  }
+}
+
+mod nickname
+{
+  use std::collections::HashMap;
+
+  use crate::{
+    decorators::Renamed,
+    v3::HasName,
+    v8::Greeter,
+  };
+
+  pub struct NicknameGreeter<G>
+  {
+    pub inner: G,
+    pub expansions: HashMap<String, String>,
+  }
+
+  impl<P, G> Greeter<P> for NicknameGreeter<G>
+  where
+    P: HasName,
+    G: for<'a> Greeter<Renamed<'a, P>>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let name = person.name();
+
+      let name = match self.expansions.get(&name) {
+        Some(canonical) => canonical.clone(),
+        None => name,
+      };
+
+      self.inner.greet(&Renamed::new(person, name))
+    }
+  }
+
+  #[test]
+  fn test_nickname_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let mut expansions = HashMap::new();
+    expansions.insert("Bob".to_string(), "Robert".to_string());
+
+    let greeter = NicknameGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+      expansions,
+    };
+
+    assert_eq!(greeter.greet(&CasualPerson::new("Bob")), "Hello, Robert!");
+    assert_eq!(greeter.greet(&CasualPerson::new("Alice")), "Hello, Alice!");
+  }
+
+  #[test]
+  fn test_nickname_greeter_keeps_person()
+  {
+    use crate::{
+      age::AgeGreeter,
+      v3::CasualPerson,
+    };
+
+    let mut expansions = HashMap::new();
+    expansions.insert("Bob".to_string(), "Robert".to_string());
+
+    let greeter = NicknameGreeter {
+      inner: AgeGreeter,
+      expansions,
+    };
+
+    let mut bob = CasualPerson::new("Bob");
+    bob.age = Some(8);
+
+    assert_eq!(greeter.greet(&bob), "Hi there, Robert!");
+  }
+}
+
+mod pool
+{
+  use std::{
+    collections::HashSet,
+    rc::Rc,
+  };
+
+  use crate::v8::Greeter;
+
+  #[derive(Default)]
+  pub struct GreetPool
+  {
+    greetings: HashSet<Rc<str>>,
+  }
+
+  impl GreetPool
+  {
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    pub fn greet<P, G: Greeter<P>>(
+      &mut self,
+      greeter: &G,
+      person: &P,
+    ) -> Rc<str>
+    {
+      let greeting = greeter.greet(person);
+
+      match self.greetings.get(greeting.as_str()) {
+        Some(interned) => interned.clone(),
+        None => {
+          let interned: Rc<str> = Rc::from(greeting);
+          self.greetings.insert(interned.clone());
+          interned
+        }
+      }
+    }
+
+    pub fn len(&self) -> usize
+    {
+      self.greetings.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+      self.greetings.is_empty()
+    }
+  }
+
+  #[test]
+  fn test_greet_pool()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = WithName(WordGreeter::new("Hello"));
+    let mut pool = GreetPool::new();
+
+    let first = pool.greet(&greeter, &CasualPerson::new("Alice"));
+    let second = pool.greet(&greeter, &CasualPerson::new("Alice"));
+    let other = pool.greet(&greeter, &CasualPerson::new("Bob"));
+
+    assert_eq!(&*first, "Hello, Alice!");
+    assert!(Rc::ptr_eq(&first, &second));
+    assert!(!Rc::ptr_eq(&first, &other));
+    assert_eq!(pool.len(), 2);
+  }
+}
+
+mod branch
+{
+  use crate::{
+    v3::HasName,
+    v8::Greeter,
+  };
+
+  pub struct LengthBranchGreeter<Short, Long>
+  {
+    pub threshold: usize,
+    pub short: Short,
+    pub long: Long,
+  }
+
+  impl<P, Short, Long> Greeter<P> for LengthBranchGreeter<Short, Long>
+  where
+    P: HasName,
+    Short: Greeter<P>,
+    Long: Greeter<P>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      if person.name().chars().count() <= self.threshold {
+        self.short.greet(person)
+      } else {
+        self.long.greet(person)
+      }
+    }
+  }
+
+  #[test]
+  fn test_length_branch_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = LengthBranchGreeter {
+      threshold: 5,
+      short: WithName(WordGreeter::new("Hi")),
+      long: WithName(WordGreeter::new("Greetings")),
+    };
+
+    assert_eq!(greeter.greet(&CasualPerson::new("Zoë")), "Hi, Zoë!");
+    assert_eq!(greeter.greet(&CasualPerson::new("Alice")), "Hi, Alice!");
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Bartholomew")),
+      "Greetings, Bartholomew!"
+    );
+  }
+
+  pub struct RelationshipGreeter<Formal, Casual>
+  {
+    pub is_close: bool,
+    pub formal: Formal,
+    pub casual: Casual,
+  }
+
+  impl<P, Formal, Casual> Greeter<P> for RelationshipGreeter<Formal, Casual>
+  where
+    Formal: Greeter<P>,
+    Casual: Greeter<P>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      if self.is_close {
+        self.casual.greet(person)
+      } else {
+        self.formal.greet(person)
+      }
+    }
+  }
+
+  #[test]
+  fn test_relationship_greeter()
+  {
+    use crate::{
+      first_name::FirstNameGreeter,
+      v3::FormalPerson,
+      v8::{
+        PoliteGreeter,
+        Unit,
+      },
+    };
+
+    let person = FormalPerson::new("Mr.", "John", "Smith");
+
+    let mut greeter = RelationshipGreeter {
+      is_close: false,
+      formal: Unit(PoliteGreeter),
+      casual: Unit(FirstNameGreeter),
+    };
+
+    assert_eq!(greeter.greet(&person), "Welcome back, Mr. Smith!");
+
+    greeter.is_close = true;
+
+    assert_eq!(greeter.greet(&person), "Hi, John!");
+  }
+
+  pub struct WhenGreeter<P, G>
+  {
+    pub pred: fn(&P) -> bool,
+    pub inner: G,
+    pub otherwise: String,
+  }
+
+  impl<P, G: Greeter<P>> Greeter<P> for WhenGreeter<P, G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      if (self.pred)(person) {
+        self.inner.greet(person)
+      } else {
+        self.otherwise.clone()
+      }
+    }
+  }
+
+  #[test]
+  fn test_when_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = WhenGreeter {
+      pred: |person: &CasualPerson| person.name.starts_with('A'),
+      inner: WithName(WordGreeter::new("Hello")),
+      otherwise: "Not now.".to_string(),
+    };
+
+    assert_eq!(greeter.greet(&CasualPerson::new("Alice")), "Hello, Alice!");
+    assert_eq!(greeter.greet(&CasualPerson::new("Bob")), "Not now.");
+  }
+}
+
+mod bidi
+{
+  use crate::{
+    decorators::Renamed,
+    v3::HasName,
+    v5::AnyPerson,
+    v8::Greeter,
+  };
+
+  const RIGHT_TO_LEFT_ISOLATE: char = '\u{2067}';
+  const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+  pub fn detect_rtl(name: &str) -> bool
+  {
+    name.chars().any(|c| {
+      matches!(
+        c,
+        '\u{0590}'..='\u{08FF}'
+          | '\u{FB1D}'..='\u{FDFF}'
+          | '\u{FE70}'..='\u{FEFC}'
+      )
+    })
+  }
+
+  pub struct RtlGreeter<G>
+  {
+    pub inner: G,
+  }
+
+  impl<P, G> Greeter<P> for RtlGreeter<G>
+  where
+    P: HasName,
+    G: for<'a> Greeter<Renamed<'a, P>>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let name = person.name();
+
+      if detect_rtl(&name) {
+        let isolated = format!(
+          "{}{}{}",
+          RIGHT_TO_LEFT_ISOLATE, name, POP_DIRECTIONAL_ISOLATE
+        );
+
+        self.inner.greet(&Renamed::new(person, isolated))
+      } else {
+        self.inner.greet(&Renamed::new(person, name))
+      }
+    }
+  }
+
+  #[test]
+  fn test_rtl_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = RtlGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+    };
+
+    assert!(detect_rtl("דוד"));
+    assert!(detect_rtl("فاطمة"));
+    assert!(!detect_rtl("Alice"));
+    assert!(!detect_rtl("\u{FEFF}Alice"));
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("דוד")),
+      "Hello, \u{2067}דוד\u{2069}!"
+    );
+    assert_eq!(greeter.greet(&CasualPerson::new("Alice")), "Hello, Alice!");
+  }
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum TextDir
+  {
+    Ltr,
+    Rtl,
+  }
+
+  impl TextDir
+  {
+    pub fn of(name: &str) -> Self
+    {
+      if detect_rtl(name) {
+        TextDir::Rtl
+      } else {
+        TextDir::Ltr
+      }
+    }
+  }
+
+  pub fn greet_with_dir(
+    greeter: &impl Greeter<AnyPerson>,
+    persons: &[AnyPerson],
+  ) -> Vec<(String, TextDir)>
+  {
+    persons
+      .iter()
+      .map(|person| (greeter.greet(person), TextDir::of(&person.name())))
+      .collect()
+  }
+
+  #[test]
+  fn test_greet_with_dir()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    let persons = vec![
+      AnyPerson::casual(CasualPerson::new("Alice")),
+      AnyPerson::casual(CasualPerson::new("فاطمة")),
+    ];
+
+    assert_eq!(
+      greet_with_dir(&AnyGreeter::polite(PoliteGreeter), &persons),
+      vec![
+        ("Hello, Alice!".to_string(), TextDir::Ltr),
+        ("Hello, فاطمة!".to_string(), TextDir::Rtl),
+      ]
+    );
+
+    let persons = vec![AnyPerson::casual(CasualPerson::new("\u{FEFF}Alice"))];
+
+    assert_eq!(
+      greet_with_dir(&AnyGreeter::polite(PoliteGreeter), &persons)[0].1,
+      TextDir::Ltr
+    );
+  }
+}
+
+mod first_name
+{
+  use crate::{
+    v3::{
+      Anonymous,
+      CasualPerson,
+      FormalPerson,
+      HasName,
+    },
+    v8::{
+      Greeter,
+      Unit,
+    },
+  };
+
+  pub struct FirstNameGreeter;
+
+  impl Greeter<FormalPerson> for Unit<FirstNameGreeter>
+  {
+    fn greet(
+      &self,
+      person: &FormalPerson,
+    ) -> String
+    {
+      format!("Hi, {}!", person.first_name)
+    }
+  }
+
+  impl Greeter<CasualPerson> for Unit<FirstNameGreeter>
+  {
+    fn greet(
+      &self,
+      person: &CasualPerson,
+    ) -> String
+    {
+      format!("Hi, {}!", person.name())
+    }
+  }
+
+  impl Greeter<Anonymous> for Unit<FirstNameGreeter>
+  {
+    fn greet(
+      &self,
+      person: &Anonymous,
+    ) -> String
+    {
+      format!("Hi, {}!", person.name())
+    }
+  }
+
+  #[test]
+  fn test_first_name_greeter()
+  {
+    use crate::v5::make_persons;
+
+    let greeter = Unit(FirstNameGreeter);
+
+    assert_eq!(
+      greeter.greet(&FormalPerson::new("Mr.", "John", "Smith")),
+      "Hi, John!"
+    );
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Alice Liddell")),
+      "Hi, Alice Liddell!"
+    );
+
+    let greetings: Vec<String> = make_persons()
+      .iter()
+      .map(|person| greeter.greet(&person.0))
+      .collect();
+
+    assert_eq!(
+      greetings,
+      vec!["Hi, John!", "Hi, Alice!", "Hi, Anonymous #8!"]
+    );
+  }
+}
+
+mod checksum
+{
+  use crate::{
+    v3::HasName,
+    v5::AnyPerson,
+    v8::Greeter,
+  };
+
+  const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+  const FNV_PRIME: u64 = 0x100000001b3;
+
+  pub fn fnv1a(bytes: &[u8]) -> u64
+  {
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+      (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+  }
+
+  pub fn name_fingerprint(person: &impl HasName) -> u64
+  {
+    fnv1a(person.name().as_bytes())
+  }
+
+  pub struct ChecksumGreeter<G>
+  {
+    pub inner: G,
+  }
+
+  impl<P: HasName, G: Greeter<P>> Greeter<P> for ChecksumGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let hash = format!("{:016x}", name_fingerprint(person));
+
+      format!("{} [{}]", self.inner.greet(person), &hash[..4])
+    }
+  }
+
+  #[test]
+  fn test_checksum_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = ChecksumGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+    };
+
+    let alice = greeter.greet(&CasualPerson::new("Alice"));
+
+    assert_eq!(alice, "Hello, Alice! [1239]");
+    assert_eq!(greeter.greet(&CasualPerson::new("Alice")), alice);
+    assert_ne!(greeter.greet(&CasualPerson::new("Bob")), alice);
+  }
+
+  #[test]
+  fn test_name_fingerprint()
+  {
+    use crate::v3::CasualPerson;
+
+    assert_eq!(
+      name_fingerprint(&CasualPerson::new("Alice")),
+      0x123909cb9f15d167
+    );
+    assert_eq!(name_fingerprint(&CasualPerson::new("")), 0xcbf29ce484222325);
+  }
+
+  pub fn greet_with_keys(
+    greeter_id: &str,
+    greeter: &impl Greeter<AnyPerson>,
+    persons: &[AnyPerson],
+  ) -> Vec<(String, String)>
+  {
+    persons
+      .iter()
+      .map(|person| {
+        let greeting = greeter.greet(person);
+
+        let mut bytes = greeter_id.as_bytes().to_vec();
+        bytes.push(0);
+        bytes.extend_from_slice(&name_fingerprint(person).to_be_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(greeting.as_bytes());
+
+        (format!("{:016x}", fnv1a(&bytes)), greeting)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_greet_with_keys()
+  {
+    use crate::{
+      v5::make_persons,
+      v6::WordGreeter,
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+        WithName,
+      },
+    };
+
+    let persons = make_persons();
+    let greeter = AnyGreeter::polite(PoliteGreeter);
+
+    let keyed = greet_with_keys("polite", &greeter, &persons);
+
+    assert_eq!(keyed, greet_with_keys("polite", &greeter, &persons));
+    assert_eq!(keyed[1].1, "Hello, Alice!");
+    assert_ne!(keyed[0].0, keyed[1].0);
+    assert_ne!(keyed[1].0, keyed[2].0);
+    assert_ne!(keyed[0].0, keyed[2].0);
+
+    let other = WithName(WordGreeter::new("Hello"));
+    let other_keyed = greet_with_keys("polite", &other, &persons);
+
+    assert_eq!(other_keyed[1], keyed[1]);
+    assert_eq!(keyed[1].0, "eddc9d58f25e9cca");
+
+    let other_keyed = greet_with_keys("word", &other, &persons);
+
+    assert_eq!(other_keyed[1].1, keyed[1].1);
+    assert_ne!(other_keyed[1].0, keyed[1].0);
+  }
+}
+
+mod cache
+{
+  use std::{
+    cell::RefCell,
+    collections::HashMap,
+    hash::Hash,
+  };
+
+  use crate::v8::Greeter;
+
+  pub struct GreetingCache<G, P>
+  {
+    pub inner: G,
+    greetings: RefCell<HashMap<P, String>>,
+  }
+
+  impl<G, P> GreetingCache<G, P>
+  {
+    pub fn new(inner: G) -> Self
+    {
+      Self {
+        inner,
+        greetings: RefCell::new(HashMap::new()),
+      }
+    }
+  }
+
+  impl<P, G> Greeter<P> for GreetingCache<G, P>
+  where
+    P: Hash + Eq + Clone,
+    G: Greeter<P>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      if let Some(greeting) = self.greetings.borrow().get(person) {
+        return greeting.clone();
+      }
+
+      let greeting = self.inner.greet(person);
+
+      self
+        .greetings
+        .borrow_mut()
+        .insert(person.clone(), greeting.clone());
+
+      greeting
+    }
+  }
+
+  pub trait GreeterExt<P>: Greeter<P> + Sized
+  {
+    fn cached(self) -> GreetingCache<Self, P>
+    {
+      GreetingCache::new(self)
+    }
+  }
+
+  impl<P, G: Greeter<P>> GreeterExt<P> for G {}
+
+  #[test]
+  fn test_cached()
+  {
+    use crate::{
+      decorators::CountingGreeter,
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter =
+      CountingGreeter::new(WithName(WordGreeter::new("Hi"))).cached();
+    let alice = CasualPerson::new("Alice");
+
+    assert_eq!(greeter.greet(&alice), "Hi, Alice!");
+    assert_eq!(greeter.greet(&alice), "Hi, Alice!");
+    assert_eq!(greeter.inner.count(), 1);
+
+    assert_eq!(greeter.greet(&CasualPerson::new("Bob")), "Hi, Bob!");
+    assert_eq!(greeter.inner.count(), 2);
+  }
+
+  #[test]
+  fn test_cached_same_name_different_kind()
+  {
+    use crate::{
+      v3::{
+        CasualPerson,
+        FormalPerson,
+      },
+      v5::AnyPerson,
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    let greeter = AnyGreeter::polite(PoliteGreeter).cached();
+
+    let casual: AnyPerson = CasualPerson::new("Mr. John Smith").into();
+    let formal: AnyPerson = FormalPerson::new("Mr.", "John", "Smith").into();
+
+    assert_eq!(greeter.greet(&casual), "Hello, Mr. John Smith!");
+    assert_eq!(greeter.greet(&formal), "Welcome back, Mr. Smith!");
+  }
+
+  #[test]
+  fn test_cached_wrapped_person()
+  {
+    use crate::{
+      age::Aged,
+      decorators::CountingGreeter,
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter =
+      CountingGreeter::new(WithName(WordGreeter::new("Hi"))).cached();
+    let aged = |age| Aged {
+      person: CasualPerson::new("Alice"),
+      age,
+    };
+
+    assert_eq!(greeter.greet(&aged(Some(30))), "Hi, Alice!");
+    assert_eq!(greeter.greet(&aged(Some(30))), "Hi, Alice!");
+    assert_eq!(greeter.inner.count(), 1);
+
+    assert_eq!(greeter.greet(&aged(None)), "Hi, Alice!");
+    assert_eq!(greeter.inner.count(), 2);
+  }
+}
+
+mod group
+{
+  use std::collections::BTreeMap;
+
+  use crate::{
+    v3::HasName,
+    v8::Greeter,
+  };
+
+  pub fn join_names(names: &[String]) -> String
+  {
+    join_names_with(names, "and")
+  }
+
+  pub fn join_names_with(
+    names: &[String],
+    and_word: &str,
+  ) -> String
+  {
+    match names {
+      [] => String::new(),
+      [name] => name.clone(),
+      [init @ .., last] => {
+        format!("{} {} {}", init.join(", "), and_word, last)
+      }
+    }
+  }
+
+  pub trait JoinStrategy
+  {
+    fn join(
+      &self,
+      parts: &[String],
+    ) -> String;
+  }
+
+  pub struct CommaJoin;
+
+  pub struct NewlineJoin;
+
+  pub struct AndJoin;
+
+  pub struct AndWordJoin(pub &'static str);
+
+  impl JoinStrategy for CommaJoin
+  {
+    fn join(
+      &self,
+      parts: &[String],
+    ) -> String
+    {
+      parts.join(", ")
+    }
+  }
+
+  impl JoinStrategy for NewlineJoin
+  {
+    fn join(
+      &self,
+      parts: &[String],
+    ) -> String
+    {
+      parts.join("\n")
+    }
+  }
+
+  impl JoinStrategy for AndJoin
+  {
+    fn join(
+      &self,
+      parts: &[String],
+    ) -> String
+    {
+      join_names(parts)
+    }
+  }
+
+  impl JoinStrategy for AndWordJoin
+  {
+    fn join(
+      &self,
+      parts: &[String],
+    ) -> String
+    {
+      join_names_with(parts, self.0)
+    }
+  }
+
+  pub fn greet_group<P: HasName>(
+    persons: &[P],
+    join: &dyn JoinStrategy,
+  ) -> String
+  {
+    let names: Vec<String> = persons.iter().map(HasName::name).collect();
+
+    format!("Hello, {}!", join.join(&names))
+  }
+
+  impl<P: HasName> HasName for Vec<P>
+  {
+    fn name(&self) -> String
+    {
+      let names: Vec<String> = self.iter().map(HasName::name).collect();
+      AndJoin.join(&names)
+    }
+  }
+
+  pub struct JoinedNames<'a, P>
+  {
+    pub persons: &'a [P],
+    pub join: &'a dyn JoinStrategy,
+  }
+
+  impl<'a, P: HasName> HasName for JoinedNames<'a, P>
+  {
+    fn name(&self) -> String
+    {
+      let names: Vec<String> = self.persons.iter().map(HasName::name).collect();
+      self.join.join(&names)
+    }
+  }
+
+  impl<A: HasName, B: HasName> HasName for (A, B)
+  {
+    fn name(&self) -> String
+    {
+      AndJoin.join(&[self.0.name(), self.1.name()])
+    }
+  }
+
+  impl<A: HasName, B: HasName, C: HasName> HasName for (A, B, C)
+  {
+    fn name(&self) -> String
+    {
+      AndJoin.join(&[self.0.name(), self.1.name(), self.2.name()])
+    }
+  }
+
+  pub fn wrap_words(
+    text: &str,
+    width: usize,
+  ) -> String
+  {
+    text
+      .split('\n')
+      .map(|line| wrap_line(line, width))
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+
+  fn wrap_line(
+    text: &str,
+    width: usize,
+  ) -> String
+  {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+      if line.is_empty() {
+        line.push_str(word);
+      } else if line.chars().count() + 1 + word.chars().count() <= width {
+        line.push(' ');
+        line.push_str(word);
+      } else {
+        lines.push(line);
+        line = word.to_string();
+      }
+    }
+
+    if !line.is_empty() {
+      lines.push(line);
+    }
+
+    lines.join("\n")
+  }
+
+  pub fn greet_group_wrapped<P: HasName>(
+    persons: &[P],
+    join: &dyn JoinStrategy,
+    width: usize,
+  ) -> String
+  {
+    wrap_words(&greet_group(persons, join), width)
+  }
+
+  #[test]
+  fn test_group_names()
+  {
+    use crate::{
+      v3::{
+        greet_many_generic,
+        Anonymous,
+        CasualPerson,
+        FormalPerson,
+      },
+      v5::make_persons,
+    };
+
+    let trio = (
+      FormalPerson::new("Mr.", "John", "Smith"),
+      CasualPerson::new("Alice"),
+      Anonymous::new(8),
+    );
+
+    assert_eq!(
+      greet_many_generic(&[trio]),
+      vec!["Hello, Mr. John Smith, Alice and Anonymous #8!"]
+    );
+
+    let pair = (CasualPerson::new("Alice"), CasualPerson::new("Bob"));
+
+    assert_eq!(pair.name(), "Alice and Bob");
+    assert_eq!(
+      JoinedNames {
+        persons: &[pair.0, pair.1],
+        join: &CommaJoin,
+      }
+      .name(),
+      "Alice, Bob"
+    );
+    assert_eq!(
+      make_persons().name(),
+      "Mr. John Smith, Alice and Anonymous #8"
+    );
+  }
+
+  #[test]
+  fn test_greet_group_wrapped()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v5::make_persons,
+    };
+
+    let persons = vec![CasualPerson::new("Alice"), CasualPerson::new("Bob")];
+
+    assert_eq!(
+      greet_group_wrapped(&persons, &AndJoin, 80),
+      "Hello, Alice and Bob!"
+    );
+
+    assert_eq!(
+      greet_group_wrapped(&make_persons(), &AndJoin, 20),
+      "Hello, Mr. John\nSmith, Alice and\nAnonymous #8!"
+    );
+
+    let persons = vec![CasualPerson::new("Wolfeschlegelsteinhausen")];
+
+    assert_eq!(
+      greet_group_wrapped(&persons, &AndJoin, 10),
+      "Hello,\nWolfeschlegelsteinhausen!"
+    );
+
+    let persons = vec![CasualPerson::new("Alice"), CasualPerson::new("Bob")];
+
+    assert_eq!(
+      greet_group_wrapped(&persons, &NewlineJoin, 80),
+      greet_group(&persons, &NewlineJoin)
+    );
+    assert_eq!(
+      greet_group_wrapped(&make_persons(), &NewlineJoin, 10),
+      "Hello, Mr.\nJohn Smith\nAlice\nAnonymous\n#8!"
+    );
+  }
+
+  #[test]
+  fn test_join_strategy()
+  {
+    use crate::v3::CasualPerson;
+
+    let persons = vec![
+      CasualPerson::new("Alice"),
+      CasualPerson::new("Bob"),
+      CasualPerson::new("Carol"),
+    ];
+
+    assert_eq!(
+      greet_group(&persons, &AndJoin),
+      "Hello, Alice, Bob and Carol!"
+    );
+    assert_eq!(
+      greet_group(&persons, &CommaJoin),
+      "Hello, Alice, Bob, Carol!"
+    );
+    assert_eq!(
+      greet_group(&persons, &NewlineJoin),
+      "Hello, Alice\nBob\nCarol!"
+    );
+  }
+
+  pub fn greet_grouped_by_initial<P: HasName>(
+    greeter: &impl Greeter<P>,
+    persons: &[P],
+  ) -> String
+  {
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for person in persons {
+      let header = match person.name().chars().next() {
+        Some(c) if c.is_alphabetic() => c.to_uppercase().collect(),
+        _ => "#".to_string(),
+      };
+
+      groups
+        .entry(header)
+        .or_default()
+        .push(greeter.greet(person));
+    }
+
+    groups
+      .into_iter()
+      .flat_map(|(header, greetings)| {
+        std::iter::once(header).chain(
+          greetings
+            .into_iter()
+            .map(|greeting| format!("  {}", greeting)),
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+
+  #[test]
+  fn test_greet_grouped_by_initial()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = WithName(WordGreeter::new("Hello"));
+
+    let persons = [
+      CasualPerson::new("bob"),
+      CasualPerson::new("Alice"),
+      CasualPerson::new("Bea"),
+      CasualPerson::new("42"),
+    ];
+
+    assert_eq!(
+      greet_grouped_by_initial(&greeter, &persons),
+      "#\n  Hello, 42!\n\
+       A\n  Hello, Alice!\n\
+       B\n  Hello, bob!\n  Hello, Bea!"
+    );
+  }
+}
+
+mod locale
+{
+  use crate::{
+    group::{
+      AndWordJoin,
+      JoinStrategy,
+    },
+    v3::{
+      FormalPerson,
+      HasName,
+    },
+    v8::{
+      Greeter,
+      NameGreeter,
+    },
+  };
+
+  #[derive(Clone, Copy, Debug, PartialEq)]
+  pub enum Locale
+  {
+    En,
+    EnAu,
+    Fr,
+    FrCa,
+    De,
+    DeAt,
+    Es,
+    Ja,
+  }
+
+  impl Locale
+  {
+    pub fn greet_word(&self) -> Option<&'static str>
+    {
+      match self {
+        Self::En => Some("Hello"),
+        Self::EnAu => Some("G'day"),
+        Self::Fr => Some("Bonjour"),
+        Self::FrCa => None,
+        Self::De => Some("Hallo"),
+        Self::DeAt => Some("Servus"),
+        Self::Es => Some("Hola"),
+        Self::Ja => Some("Konnichiwa"),
+      }
+    }
+  }
+
+  impl Locale
+  {
+    pub fn and_word(&self) -> &'static str
+    {
+      match self {
+        Self::En | Self::EnAu => "and",
+        Self::Fr | Self::FrCa => "et",
+        Self::De | Self::DeAt => "und",
+        Self::Es => "y",
+        Self::Ja => "to",
+      }
+    }
+
+    pub fn and_join(&self) -> AndWordJoin
+    {
+      AndWordJoin(self.and_word())
+    }
+
+    pub fn family_name_first(&self) -> bool
+    {
+      matches!(self, Self::Ja)
+    }
+
+    pub fn base(&self) -> Locale
+    {
+      match self {
+        Self::EnAu => Self::En,
+        Self::FrCa => Self::Fr,
+        Self::DeAt => Self::De,
+        _ => *self,
+      }
+    }
+  }
+
+  impl FormalPerson
+  {
+    pub fn name_localized(
+      &self,
+      locale: Locale,
+    ) -> String
+    {
+      if locale.family_name_first() {
+        format!("{} {}", self.last_name, self.first_name)
+      } else {
+        format!("{} {}", self.first_name, self.last_name)
+      }
+    }
+  }
+
+  pub fn resolve_locale(locales: &[Locale]) -> Option<Locale>
+  {
+    locales
+      .iter()
+      .copied()
+      .find(|locale| locale.greet_word().is_some())
+  }
+
+  pub fn greet_group_localized<P: HasName>(
+    persons: &[P],
+    locale: Locale,
+    join: &dyn JoinStrategy,
+  ) -> String
+  {
+    let names: Vec<String> = persons.iter().map(HasName::name).collect();
+
+    format!(
+      "{}, {}!",
+      resolve_locale(&[locale, locale.base()])
+        .and_then(|locale| locale.greet_word())
+        .unwrap_or("Hello"),
+      join.join(&names)
+    )
+  }
+
+  pub fn detect_locale(name: &str) -> Option<Locale>
+  {
+    name.chars().find_map(|c| match c {
+      'ñ' | 'Ñ' => Some(Locale::Es),
+      'ß' | 'ä' | 'ö' | 'ü' | 'Ä' | 'Ö' | 'Ü' => Some(Locale::De),
+      'ç' | 'é' | 'è' | 'ê' | 'Ç' | 'É' | 'È' | 'Ê' => Some(Locale::Fr),
+      _ => None,
+    })
+  }
+
+  pub struct LocalizedGreeter
+  {
+    pub locales: Vec<Locale>,
+    pub respect_name_language: bool,
+    pub localize_name_order: bool,
+  }
+
+  impl LocalizedGreeter
+  {
+    pub fn greet_word(&self) -> &'static str
+    {
+      resolve_locale(&self.locales)
+        .and_then(|locale| locale.greet_word())
+        .unwrap_or("Hello")
+    }
+
+    fn locale_for(
+      &self,
+      name: &str,
+    ) -> Option<Locale>
+    {
+      let detected = if self.respect_name_language {
+        detect_locale(name).filter(|locale| locale.greet_word().is_some())
+      } else {
+        None
+      };
+
+      detected.or_else(|| resolve_locale(&self.locales))
+    }
+  }
+
+  impl<P: HasName> Greeter<P> for LocalizedGreeter
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let name = person.name();
+      let locale = self.locale_for(&name);
+
+      let name = match locale {
+        Some(locale)
+          if self.localize_name_order && locale.family_name_first() =>
+        {
+          person.name_family_first()
+        }
+        _ => name,
+      };
+
+      format!(
+        "{}, {}!",
+        locale
+          .and_then(|locale| locale.greet_word())
+          .unwrap_or("Hello"),
+        name
+      )
+    }
+  }
+
+  #[test]
+  fn test_locale_fallback_chain()
+  {
+    use crate::v3::CasualPerson;
+
+    let person = CasualPerson::new("Alice");
+
+    let greeter = LocalizedGreeter {
+      locales: vec![Locale::FrCa, Locale::Fr, Locale::En],
+      respect_name_language: false,
+      localize_name_order: false,
+    };
+
+    assert_eq!(greeter.greet(&person), "Bonjour, Alice!");
+
+    let greeter = LocalizedGreeter {
+      locales: vec![Locale::FrCa, Locale::En],
+      respect_name_language: false,
+      localize_name_order: false,
+    };
+
+    assert_eq!(greeter.greet(&person), "Hello, Alice!");
+
+    let greeter = LocalizedGreeter {
+      locales: vec![],
+      respect_name_language: false,
+      localize_name_order: false,
+    };
+
+    assert_eq!(greeter.greet(&person), "Hello, Alice!");
+  }
+
+  #[test]
+  fn test_greet_group_localized()
+  {
+    use crate::{
+      group::CommaJoin,
+      v3::CasualPerson,
+    };
+
+    let persons = vec![
+      CasualPerson::new("Alice"),
+      CasualPerson::new("Bob"),
+      CasualPerson::new("Carol"),
+    ];
+
+    assert_eq!(
+      greet_group_localized(&persons, Locale::En, &Locale::En.and_join()),
+      "Hello, Alice, Bob and Carol!"
+    );
+    assert_eq!(
+      greet_group_localized(&persons, Locale::Fr, &Locale::Fr.and_join()),
+      "Bonjour, Alice, Bob et Carol!"
+    );
+    assert_eq!(
+      greet_group_localized(&persons, Locale::FrCa, &Locale::FrCa.and_join()),
+      "Bonjour, Alice, Bob et Carol!"
+    );
+    assert_eq!(
+      greet_group_localized(&persons, Locale::DeAt, &Locale::DeAt.and_join()),
+      "Servus, Alice, Bob und Carol!"
+    );
+    assert_eq!(
+      greet_group_localized(&persons, Locale::De, &CommaJoin),
+      "Hallo, Alice, Bob, Carol!"
+    );
+  }
+
+  #[test]
+  fn test_respect_name_language()
+  {
+    use crate::v3::CasualPerson;
+
+    let mut greeter = LocalizedGreeter {
+      locales: vec![Locale::En],
+      respect_name_language: false,
+      localize_name_order: false,
+    };
+
+    assert_eq!(greeter.greet(&CasualPerson::new("Iñigo")), "Hello, Iñigo!");
+
+    greeter.respect_name_language = true;
+
+    assert_eq!(greeter.greet(&CasualPerson::new("Iñigo")), "Hola, Iñigo!");
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Jürgen")),
+      "Hallo, Jürgen!"
+    );
+    assert_eq!(greeter.greet(&CasualPerson::new("Alice")), "Hello, Alice!");
+  }
+
+  #[test]
+  fn test_name_localized()
+  {
+    use crate::v5::AnyPerson;
+
+    let person = FormalPerson::new("Mr.", "John", "Smith");
+
+    assert_eq!(person.name_localized(Locale::En), "John Smith");
+    assert_eq!(person.name_localized(Locale::Ja), "Smith John");
+
+    let mut greeter = LocalizedGreeter {
+      locales: vec![Locale::Ja],
+      respect_name_language: false,
+      localize_name_order: true,
+    };
+
+    assert_eq!(greeter.greet(&person), "Konnichiwa, Mr. Smith John!");
+
+    let any = AnyPerson::from(person.clone());
+
+    assert_eq!(greeter.greet(&any), "Konnichiwa, Mr. Smith John!");
+
+    greeter.locales = vec![Locale::De];
+
+    assert_eq!(greeter.greet(&person), "Hallo, Mr. John Smith!");
+
+    greeter.locales = vec![Locale::FrCa, Locale::Ja];
+
+    assert_eq!(greeter.greet(&person), "Konnichiwa, Mr. Smith John!");
+
+    greeter.respect_name_language = true;
+
+    assert_eq!(
+      greeter.greet(&FormalPerson::new("Herr", "Jürgen", "Klopp")),
+      "Hallo, Herr Jürgen Klopp!"
+    );
+
+    greeter.respect_name_language = false;
+    greeter.localize_name_order = false;
+
+    assert_eq!(greeter.greet(&person), "Konnichiwa, Mr. John Smith!");
+  }
+
+  #[derive(Clone, Copy, Debug, PartialEq)]
+  pub enum Language
+  {
+    English,
+    Spanish,
+    French,
+    German,
+  }
+
+  fn language_greet_word(lang: Language) -> &'static str
+  {
+    match lang {
+      Language::English => "Hello",
+      Language::Spanish => "Hola",
+      Language::French => "Bonjour",
+      Language::German => "Hallo",
+    }
+  }
+
+  pub struct LanguageGreeter
+  {
+    pub lang: Language,
+  }
+
+  impl NameGreeter for LanguageGreeter
+  {
+    fn greet_name(
+      &self,
+      person: &impl HasName,
+    ) -> String
+    {
+      format!("{}, {}!", language_greet_word(self.lang), person.name())
+    }
+  }
+
+  impl<P: HasName> Greeter<P> for LanguageGreeter
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      self.greet_name(person)
+    }
+  }
+
+  #[test]
+  fn test_language_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v5::Either,
+      v8::{
+        PoliteGreeter,
+        Unit,
+        WithName,
+      },
+    };
+
+    let person = CasualPerson::new("Alice");
+    let greet = |lang| LanguageGreeter { lang }.greet(&person);
+
+    assert_eq!(greet(Language::English), "Hello, Alice!");
+    assert_eq!(greet(Language::Spanish), "Hola, Alice!");
+    assert_eq!(greet(Language::French), "Bonjour, Alice!");
+    assert_eq!(greet(Language::German), "Hallo, Alice!");
+
+    let wrapped = WithName(LanguageGreeter {
+      lang: Language::French,
+    });
+
+    assert_eq!(wrapped.greet(&person), "Bonjour, Alice!");
+
+    let greeters: Vec<Either<Unit<PoliteGreeter>, WithName<_>>> = vec![
+      Either::Left(Unit(PoliteGreeter)),
+      Either::Right(WithName(LanguageGreeter {
+        lang: Language::Spanish,
+      })),
+    ];
+
+    assert_eq!(greeters[0].greet(&person), "Hello, Alice!");
+    assert_eq!(greeters[1].greet(&person), "Hola, Alice!");
+  }
+}
+
+mod snapshot
+{
+  use crate::{
+    v5::AnyPerson,
+    v8::Greeter,
+  };
+
+  pub fn assert_greetings_eq(
+    greeter: &impl Greeter<AnyPerson>,
+    persons: &[AnyPerson],
+    expected: &[&str],
+  )
+  {
+    let actual: Vec<String> =
+      persons.iter().map(|person| greeter.greet(person)).collect();
+
+    let mut mismatches = Vec::new();
+
+    for i in 0..actual.len().max(expected.len()) {
+      let want = expected.get(i).copied();
+      let got = actual.get(i).map(String::as_str);
+
+      if want != got {
+        mismatches.push(format!(
+          "  index {}:\n    expected: {:?}\n    actual:   {:?}",
+          i, want, got
+        ));
+      }
+    }
+
+    if !mismatches.is_empty() {
+      panic!("greetings differ from snapshot:\n{}", mismatches.join("\n"));
+    }
+  }
+
+  #[test]
+  fn test_assert_greetings_eq()
+  {
+    use crate::{
+      v5::make_persons,
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    assert_greetings_eq(
+      &AnyGreeter::polite(PoliteGreeter),
+      &make_persons(),
+      &[
+        "Welcome back, Mr. Smith!",
+        "Hello, Alice!",
+        "Hello stranger, your ID is 8.",
+      ],
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "index 1:\n    expected: Some(\"Hi, Alice!\")")]
+  fn test_assert_greetings_eq_mismatch()
+  {
+    use crate::{
+      v5::make_persons,
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    assert_greetings_eq(
+      &AnyGreeter::polite(PoliteGreeter),
+      &make_persons(),
+      &["Welcome back, Mr. Smith!", "Hi, Alice!"],
+    );
+  }
+}
+
+mod avatar
+{
+  use crate::v3::HasName;
+
+  pub fn avatar_initials(person: &impl HasName) -> String
+  {
+    let initials: String = person
+      .name()
+      .split_whitespace()
+      .take(2)
+      .filter_map(|word| word.chars().next())
+      .flat_map(char::to_uppercase)
+      .collect();
+
+    if initials.is_empty() {
+      "?".to_string()
+    } else {
+      initials
+    }
+  }
+
+  #[test]
+  fn test_avatar_initials()
+  {
+    use crate::v3::CasualPerson;
+
+    assert_eq!(avatar_initials(&CasualPerson::new("Alice")), "A");
+    assert_eq!(avatar_initials(&CasualPerson::new("John Smith")), "JS");
+    assert_eq!(avatar_initials(&CasualPerson::new("ada m lovelace")), "AM");
+    assert_eq!(avatar_initials(&CasualPerson::new("")), "?");
+  }
+}
+
+mod registry
+{
+  use std::{
+    any::{
+      Any,
+      TypeId,
+    },
+    collections::HashMap,
+  };
+
+  use crate::{
+    v3::HasName,
+    v5::AnyPerson,
+    v8::Greeter,
+  };
+
+  type Handler = Box<dyn Fn(&dyn Any) -> Option<String>>;
+
+  #[derive(Default)]
+  pub struct TypeGreeterRegistry
+  {
+    handlers: HashMap<TypeId, Handler>,
+  }
+
+  impl TypeGreeterRegistry
+  {
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    pub fn register<P: 'static>(
+      &mut self,
+      handler: impl Fn(&P) -> String + 'static,
+    )
+    {
+      self.handlers.insert(
+        TypeId::of::<P>(),
+        Box::new(move |person| person.downcast_ref::<P>().map(&handler)),
+      );
+    }
+
+    pub fn greet(
+      &self,
+      person: &dyn Any,
+    ) -> Option<String>
+    {
+      let handler = self.handlers.get(&person.type_id())?;
+      handler(person)
+    }
+  }
+
+  #[test]
+  fn test_type_greeter_registry()
+  {
+    use crate::v3::{
+      Anonymous,
+      CasualPerson,
+    };
+
+    let mut registry = TypeGreeterRegistry::new();
+
+    registry.register(|person: &CasualPerson| format!("Hey, {}!", person.name));
+
+    assert_eq!(
+      registry.greet(&CasualPerson::new("Alice")),
+      Some("Hey, Alice!".to_string())
+    );
+    assert_eq!(registry.greet(&Anonymous::new(8)), None);
+  }
+
+  pub struct PerPersonGreeter
+  {
+    pub overrides: HashMap<String, Box<dyn Greeter<AnyPerson>>>,
+    pub default: Box<dyn Greeter<AnyPerson>>,
+  }
+
+  impl Greeter<AnyPerson> for PerPersonGreeter
+  {
+    fn greet(
+      &self,
+      person: &AnyPerson,
+    ) -> String
+    {
+      match self.overrides.get(&person.name()) {
+        Some(greeter) => greeter.greet(person),
+        None => self.default.greet(person),
+      }
+    }
+  }
+
+  #[test]
+  fn test_per_person_greeter()
+  {
+    use crate::{
+      v5::make_persons,
+      v6::WordGreeter,
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    let mut overrides: HashMap<String, Box<dyn Greeter<AnyPerson>>> =
+      HashMap::new();
+    overrides.insert(
+      "Alice".to_string(),
+      Box::new(AnyGreeter::word(WordGreeter::new("Hey bestie"))),
+    );
+
+    let greeter = PerPersonGreeter {
+      overrides,
+      default: Box::new(AnyGreeter::polite(PoliteGreeter)),
+    };
+
+    let greetings: Vec<String> = make_persons()
+      .iter()
+      .map(|person| greeter.greet(person))
+      .collect();
+
+    assert_eq!(
+      greetings,
+      vec![
+        "Welcome back, Mr. Smith!",
+        "Hey bestie, Alice!",
+        "Hello stranger, your ID is 8.",
+      ]
+    );
+  }
+}
+
+mod kind
+{
+  use std::{
+    cell::RefCell,
+    collections::HashMap,
+  };
+
+  use crate::{
+    v5::{
+      AnyPerson,
+      Either,
+    },
+    v8::Greeter,
+  };
+
+  #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+  pub enum PersonKind
+  {
+    Formal,
+    Casual,
+    Anon,
+  }
+
+  impl PersonKind
+  {
+    pub fn label(&self) -> &'static str
+    {
+      match self {
+        Self::Formal => "Formal",
+        Self::Casual => "Casual",
+        Self::Anon => "Anonymous",
+      }
+    }
+  }
+
+  impl AnyPerson
+  {
+    pub fn kind(&self) -> PersonKind
+    {
+      match &self.0 {
+        Either::Left(_) => PersonKind::Formal,
+        Either::Right(Either::Left(_)) => PersonKind::Casual,
+        Either::Right(Either::Right(_)) => PersonKind::Anon,
+      }
+    }
+  }
+
+  pub struct NumberedGreeter<G>
+  {
+    pub inner: G,
+    counts: RefCell<HashMap<PersonKind, usize>>,
+  }
+
+  impl<G> NumberedGreeter<G>
+  {
+    pub fn new(inner: G) -> Self
+    {
+      Self {
+        inner,
+        counts: RefCell::new(HashMap::new()),
+      }
+    }
+
+    pub fn reset(&self)
+    {
+      self.counts.borrow_mut().clear();
+    }
+  }
+
+  impl<G: Greeter<AnyPerson>> Greeter<AnyPerson> for NumberedGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &AnyPerson,
+    ) -> String
+    {
+      let kind = person.kind();
+
+      let number = {
+        let mut counts = self.counts.borrow_mut();
+        let count = counts.entry(kind).or_insert(0);
+        *count += 1;
+        *count
+      };
+
+      format!("{} #{}: {}", kind.label(), number, self.inner.greet(person))
+    }
+  }
+
+  #[test]
+  fn test_numbered_greeter()
+  {
+    use crate::{
+      v3::{
+        CasualPerson,
+        FormalPerson,
+      },
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    let persons = [
+      AnyPerson::formal(FormalPerson::new("Mr.", "John", "Smith")),
+      AnyPerson::casual(CasualPerson::new("Alice")),
+      AnyPerson::formal(FormalPerson::new("Dr.", "Jane", "Doe")),
+    ];
+
+    let greeter = NumberedGreeter::new(AnyGreeter::polite(PoliteGreeter));
+
+    let greetings: Vec<String> =
+      persons.iter().map(|person| greeter.greet(person)).collect();
+
+    assert_eq!(
+      greetings,
+      vec![
+        "Formal #1: Welcome back, Mr. Smith!",
+        "Casual #1: Hello, Alice!",
+        "Formal #2: Welcome back, Dr. Doe!",
+      ]
+    );
+
+    greeter.reset();
+
+    assert_eq!(
+      greeter.greet(&persons[2]),
+      "Formal #1: Welcome back, Dr. Doe!"
+    );
+  }
+
+  pub struct PunctuationByKindGreeter<G>
+  {
+    pub inner: G,
+  }
+
+  impl<G: Greeter<AnyPerson>> Greeter<AnyPerson> for PunctuationByKindGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &AnyPerson,
+    ) -> String
+    {
+      let greeting = self.inner.greet(person);
+      let punctuation = match person.kind() {
+        PersonKind::Formal => '.',
+        PersonKind::Casual => '!',
+        PersonKind::Anon => '.',
+      };
+
+      format!("{}{}", greeting.trim_end_matches(['!', '.']), punctuation)
+    }
+  }
+
+  #[test]
+  fn test_punctuation_by_kind_greeter()
+  {
+    use crate::{
+      v5::make_persons,
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    let greeter = PunctuationByKindGreeter {
+      inner: AnyGreeter::polite(PoliteGreeter),
+    };
+
+    let greetings: Vec<String> = make_persons()
+      .iter()
+      .map(|person| greeter.greet(person))
+      .collect();
+
+    assert_eq!(
+      greetings,
+      vec![
+        "Welcome back, Mr. Smith.",
+        "Hello, Alice!",
+        "Hello stranger, your ID is 8.",
+      ]
+    );
+  }
+
+  pub struct EmojiByKindGreeter<G>
+  {
+    pub inner: G,
+    pub emojis: HashMap<PersonKind, String>,
+  }
+
+  impl<G> EmojiByKindGreeter<G>
+  {
+    pub fn new(inner: G) -> Self
+    {
+      let emojis = [
+        (PersonKind::Formal, "\u{1F3A9}"),
+        (PersonKind::Casual, "\u{1F44B}"),
+        (PersonKind::Anon, "\u{1F575}\u{FE0F}"),
+      ]
+      .iter()
+      .map(|(kind, emoji)| (*kind, emoji.to_string()))
+      .collect();
+
+      Self { inner, emojis }
+    }
+  }
+
+  impl<G: Greeter<AnyPerson>> Greeter<AnyPerson> for EmojiByKindGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &AnyPerson,
+    ) -> String
+    {
+      let greeting = self.inner.greet(person);
+
+      match self.emojis.get(&person.kind()) {
+        Some(emoji) => format!("{} {}", emoji, greeting),
+        None => greeting,
+      }
+    }
+  }
+
+  #[test]
+  fn test_emoji_by_kind_greeter()
+  {
+    use crate::{
+      v5::make_persons,
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    let mut greeter =
+      EmojiByKindGreeter::new(AnyGreeter::polite(PoliteGreeter));
+
+    let greetings: Vec<String> = make_persons()
+      .iter()
+      .map(|person| greeter.greet(person))
+      .collect();
+
+    assert_eq!(
+      greetings,
+      vec![
+        "\u{1F3A9} Welcome back, Mr. Smith!",
+        "\u{1F44B} Hello, Alice!",
+        "\u{1F575}\u{FE0F} Hello stranger, your ID is 8.",
+      ]
+    );
+
+    greeter
+      .emojis
+      .insert(PersonKind::Casual, "\u{1F600}".to_string());
+    greeter.emojis.remove(&PersonKind::Formal);
+
+    let persons = make_persons();
+
+    assert_eq!(greeter.greet(&persons[0]), "Welcome back, Mr. Smith!");
+    assert_eq!(greeter.greet(&persons[1]), "\u{1F600} Hello, Alice!");
+  }
+}
+
+mod truncate
+{
+  use crate::{
+    decorators::Renamed,
+    v3::HasName,
+    v8::Greeter,
+  };
+
+  #[cfg(not(feature = "unicode-segmentation"))]
+  pub fn truncate_name(
+    name: &str,
+    max_len: usize,
+  ) -> String
+  {
+    if name.chars().count() <= max_len {
+      name.to_string()
+    } else {
+      let truncated: String = name.chars().take(max_len).collect();
+      format!("{}…", truncated)
+    }
+  }
+
+  #[cfg(feature = "unicode-segmentation")]
+  pub fn truncate_name(
+    name: &str,
+    max_len: usize,
+  ) -> String
+  {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    if name.graphemes(true).count() <= max_len {
+      name.to_string()
+    } else {
+      let truncated: String = name.graphemes(true).take(max_len).collect();
+      format!("{}…", truncated)
+    }
+  }
+
+  pub struct TruncateGreeter<G>
+  {
+    pub inner: G,
+    pub max_len: usize,
+  }
+
+  impl<P, G> Greeter<P> for TruncateGreeter<G>
+  where
+    P: HasName,
+    G: for<'a> Greeter<Renamed<'a, P>>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let name = truncate_name(&person.name(), self.max_len);
+      self.inner.greet(&Renamed::new(person, name))
+    }
+  }
+
+  #[test]
+  fn test_truncate_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = TruncateGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+      max_len: 5,
+    };
+
+    assert_eq!(greeter.greet(&CasualPerson::new("Alice")), "Hello, Alice!");
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Bartholomew")),
+      "Hello, Barth…!"
+    );
+  }
+
+  #[cfg(feature = "unicode-segmentation")]
+  #[test]
+  fn test_truncate_graphemes()
+  {
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let name = format!("{}Smith", family);
+
+    assert_eq!(truncate_name(&name, 1), format!("{}…", family));
+  }
+
+  pub fn limit_words(
+    name: &str,
+    max_words: usize,
+  ) -> String
+  {
+    let words: Vec<&str> = name.split_whitespace().collect();
+
+    if words.len() <= max_words {
+      name.to_string()
+    } else {
+      format!("{}…", words[..max_words].join(" "))
+    }
+  }
+
+  pub struct WordLimitGreeter<G>
+  {
+    pub inner: G,
+    pub max_words: usize,
+  }
+
+  impl<P, G> Greeter<P> for WordLimitGreeter<G>
+  where
+    P: HasName,
+    G: for<'a> Greeter<Renamed<'a, P>>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let name = limit_words(&person.name(), self.max_words);
+      self.inner.greet(&Renamed::new(person, name))
+    }
+  }
+
+  #[test]
+  fn test_word_limit_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = WordLimitGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+      max_words: 2,
+    };
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("John Smith")),
+      "Hello, John Smith!"
+    );
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("John Jacob Jingleheimer Schmidt")),
+      "Hello, John Jacob…!"
+    );
+  }
+}
+
+#[cfg(feature = "async")]
+mod async_greet
+{
+  use std::{
+    future::{
+      self,
+      Future,
+    },
+    pin::Pin,
+    task::{
+      Context,
+      Poll,
+    },
+    time::Duration,
+  };
+
+  use futures::{
+    stream,
+    Stream,
+    StreamExt,
+  };
+
+  use crate::v7;
+
+  pub trait AsyncGreeter<Person>
+  {
+    fn greet(
+      &self,
+      person: &Person,
+    ) -> impl Future<Output = String>;
+  }
+
+  pub async fn async_greet_many<P, G: AsyncGreeter<P>>(
+    greeter: &G,
+    persons: &[P],
+  ) -> Vec<String>
+  {
+    let mut greetings = Vec::with_capacity(persons.len());
+
+    for person in persons {
+      greetings.push(greeter.greet(person).await);
+    }
+
+    greetings
+  }
+
+  pub struct SyncGreeter<G>(pub G);
+
+  impl<P, G: v7::Greeter<P>> AsyncGreeter<P> for SyncGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> impl Future<Output = String>
+    {
+      future::ready(self.0.greet(person))
+    }
+  }
+
+  pub struct GreetStream<'a>
+  {
+    greetings: Pin<Box<dyn Stream<Item = String> + 'a>>,
+  }
+
+  impl<'a> GreetStream<'a>
+  {
+    pub fn new<P, G: AsyncGreeter<P>>(
+      greeter: &'a G,
+      persons: &'a [P],
+    ) -> Self
+    {
+      Self {
+        greetings: Box::pin(
+          stream::iter(persons).then(move |person| greeter.greet(person)),
+        ),
+      }
+    }
+  }
+
+  impl Stream for GreetStream<'_>
+  {
+    type Item = String;
+
+    fn poll_next(
+      mut self: Pin<&mut Self>,
+      cx: &mut Context<'_>,
+    ) -> Poll<Option<String>>
+    {
+      self.greetings.as_mut().poll_next(cx)
+    }
+  }
+
+  pub trait AsyncTryGreeter<Person>
+  {
+    type Error;
+
+    fn try_greet(
+      &self,
+      person: &Person,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+  }
+
+  pub struct RetryGreeter<G>
+  {
+    pub inner: G,
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_total_delay: Duration,
+  }
+
+  impl<G> RetryGreeter<G>
+  {
+    pub async fn greet<P>(
+      &self,
+      person: &P,
+    ) -> Result<String, G::Error>
+    where
+      G: AsyncTryGreeter<P>,
+    {
+      let mut attempt = 1;
+      let mut delay = self.base_delay;
+      let mut total_delay = Duration::ZERO;
+
+      loop {
+        match self.inner.try_greet(person).await {
+          Ok(greeting) => return Ok(greeting),
+          Err(err) if attempt >= self.max_attempts => return Err(err),
+          Err(_) => {
+            let wait = delay.min(self.max_total_delay - total_delay);
+
+            tokio::time::sleep(wait).await;
+
+            total_delay += wait;
+            delay = delay.saturating_mul(2);
+            attempt += 1;
+          }
+        }
+      }
+    }
+  }
+
+  #[cfg(test)]
+  #[tokio::test]
+  async fn test_greet_stream()
+  {
+    use crate::{
+      v3::HasName,
+      v5::make_persons,
+    };
+
+    struct RemoteGreeter;
+
+    impl<P: HasName> AsyncGreeter<P> for RemoteGreeter
+    {
+      async fn greet(
+        &self,
+        person: &P,
+      ) -> String
+      {
+        tokio::task::yield_now().await;
+        format!("Hello, {}!", person.name())
+      }
+    }
+
+    let persons = make_persons();
+
+    let greetings: Vec<String> =
+      GreetStream::new(&RemoteGreeter, &persons).collect().await;
+
+    assert_eq!(
+      greetings,
+      vec![
+        "Hello, Mr. John Smith!",
+        "Hello, Alice!",
+        "Hello, Anonymous #8!"
+      ]
+    );
+  }
+
+  #[cfg(test)]
+  #[tokio::test(start_paused = true)]
+  async fn test_retry_greeter()
+  {
+    use std::cell::Cell;
+
+    use tokio::time::Instant;
+
+    use crate::v3::{
+      CasualPerson,
+      HasName,
+    };
+
+    struct FlakyGreeter
+    {
+      failures_left: Cell<u32>,
+    }
+
+    impl<P: HasName> AsyncTryGreeter<P> for FlakyGreeter
+    {
+      type Error = &'static str;
+
+      async fn try_greet(
+        &self,
+        person: &P,
+      ) -> Result<String, &'static str>
+      {
+        if self.failures_left.get() > 0 {
+          self.failures_left.set(self.failures_left.get() - 1);
+          Err("unavailable")
+        } else {
+          Ok(format!("Hello, {}!", person.name()))
+        }
+      }
+    }
+
+    let greeter = RetryGreeter {
+      inner: FlakyGreeter {
+        failures_left: Cell::new(2),
+      },
+      max_attempts: 5,
+      base_delay: Duration::from_millis(100),
+      max_total_delay: Duration::from_secs(1),
+    };
+
+    let start = Instant::now();
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Alice")).await,
+      Ok("Hello, Alice!".to_string())
+    );
+    assert_eq!(start.elapsed(), Duration::from_millis(300));
+
+    let greeter = RetryGreeter {
+      inner: FlakyGreeter {
+        failures_left: Cell::new(10),
+      },
+      max_attempts: 3,
+      ..greeter
+    };
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Alice")).await,
+      Err("unavailable")
+    );
+  }
+
+  #[cfg(test)]
+  #[tokio::test]
+  async fn test_async_greet_many()
+  {
+    use crate::{
+      v3::HasName,
+      v5::{
+        make_persons,
+        AnyPerson,
+      },
+    };
+
+    struct NameGreeter;
+
+    impl v7::Greeter<AnyPerson> for NameGreeter
+    {
+      fn greet(
+        &self,
+        person: &AnyPerson,
+      ) -> String
+      {
+        format!("Hello, {}!", person.name())
+      }
+    }
+
+    let persons = make_persons();
+
+    let sync: Vec<String> = persons
+      .iter()
+      .map(|person| v7::Greeter::greet(&NameGreeter, person))
+      .collect();
+
+    assert_eq!(
+      async_greet_many(&SyncGreeter(NameGreeter), &persons).await,
+      sync
+    );
+  }
+
+  #[cfg(test)]
+  #[tokio::test(start_paused = true)]
+  async fn test_retry_greeter_many_attempts()
+  {
+    use tokio::time::Instant;
+
+    use crate::v3::CasualPerson;
+
+    struct DownGreeter;
+
+    impl<P> AsyncTryGreeter<P> for DownGreeter
+    {
+      type Error = &'static str;
+
+      async fn try_greet(
+        &self,
+        _person: &P,
+      ) -> Result<String, &'static str>
+      {
+        Err("unavailable")
+      }
+    }
+
+    let greeter = RetryGreeter {
+      inner: DownGreeter,
+      max_attempts: 100,
+      base_delay: Duration::from_millis(100),
+      max_total_delay: Duration::from_secs(1),
+    };
+
+    let start = Instant::now();
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Alice")).await,
+      Err("unavailable")
+    );
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+  }
+}
+
+mod capitalize
+{
+  use crate::{
+    v3::HasName,
+    v6::WordGreeter,
+    v8::NameGreeter,
+  };
+
+  pub fn capitalize_first(word: &str) -> String
+  {
+    let mut chars = word.chars();
+
+    match chars.next() {
+      Some(first) => first.to_uppercase().chain(chars).collect(),
+      None => String::new(),
+    }
+  }
+
+  pub struct CapitalizeWordGreeter(pub WordGreeter);
+
+  impl NameGreeter for CapitalizeWordGreeter
+  {
+    fn greet_name(
+      &self,
+      person: &impl HasName,
+    ) -> String
+    {
+      format!(
+        "{}, {}!",
+        capitalize_first(&self.0.greet_word),
+        person.name()
+      )
+    }
+  }
+
+  #[test]
+  fn test_capitalize_word_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v8::{
+        Greeter,
+        WithName,
+      },
+    };
+
+    let person = CasualPerson::new("alice");
+
+    let greeter = WithName(CapitalizeWordGreeter(WordGreeter::new("welcome")));
+    assert_eq!(greeter.greet(&person), "Welcome, alice!");
+
+    let greeter = WithName(CapitalizeWordGreeter(WordGreeter::new("Hello")));
+    assert_eq!(greeter.greet(&person), "Hello, alice!");
+
+    assert_eq!(capitalize_first("élan vital"), "Élan vital");
+    assert_eq!(capitalize_first(""), "");
+  }
+}
+
+mod report
+{
+  use crate::{
+    kind::PersonKind,
+    v3::HasName,
+    v5::AnyPerson,
+    v8::Greeter,
+  };
+
+  pub fn greet_report<P: HasName>(
+    title: &str,
+    greeter: &impl Greeter<P>,
+    persons: &[P],
+  ) -> String
+  {
+    let mut report = format!("=== {} ({}) ===\n", title, persons.len());
+
+    for person in persons {
+      report.push_str(&greeter.greet(person));
+      report.push('\n');
+    }
+
+    report
+  }
+
+  #[test]
+  fn test_greet_report()
+  {
+    use crate::{
+      v5::make_persons,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let report = greet_report(
+      "Greetings",
+      &WithName(WordGreeter::new("Hello")),
+      &make_persons(),
+    );
+
+    assert!(report.starts_with("=== Greetings (3) ===\n"));
+    assert_eq!(report.lines().count(), 4);
+    assert_eq!(report.lines().nth(2), Some("Hello, Alice!"));
+  }
+
+  pub fn greet_table(
+    greeter: &impl Greeter<AnyPerson>,
+    persons: &[AnyPerson],
+  ) -> String
+  {
+    let rows: Vec<[String; 3]> = persons
+      .iter()
+      .map(|person| {
+        [
+          person.kind().label().to_string(),
+          person.name(),
+          greeter.greet(person),
+        ]
+      })
+      .collect();
+
+    let width = |column: usize| {
+      rows
+        .iter()
+        .map(|row| row[column].chars().count())
+        .max()
+        .unwrap_or(0)
+    };
+
+    let (kind_width, name_width) = (width(0), width(1));
+
+    rows
+      .iter()
+      .map(|[kind, name, greeting]| {
+        format!(
+          "{:kind_width$} | {:name_width$} | {}",
+          kind,
+          name,
+          greeting,
+          kind_width = kind_width,
+          name_width = name_width
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+
+  #[test]
+  fn test_greet_table()
+  {
+    use crate::{
+      v5::make_persons,
+      v8::{
+        AnyGreeter,
+        PoliteGreeter,
+      },
+    };
+
+    let table =
+      greet_table(&AnyGreeter::polite(PoliteGreeter), &make_persons());
+
+    assert_eq!(
+      table,
+      "Formal    | Mr. John Smith | Welcome back, Mr. Smith!\n\
+       Casual    | Alice          | Hello, Alice!\n\
+       Anonymous | Anonymous #8   | Hello stranger, your ID is 8."
+    );
+
+    let separators: Vec<Vec<usize>> = table
+      .lines()
+      .map(|line| line.match_indices('|').map(|(i, _)| i).collect())
+      .collect();
+
+    assert!(separators.windows(2).all(|pair| pair[0] == pair[1]));
+  }
+
+  pub fn greet_with_footer(
+    greeter: &impl Greeter<AnyPerson>,
+    persons: &[AnyPerson],
+  ) -> String
+  {
+    let count = |kind: PersonKind| {
+      persons
+        .iter()
+        .filter(|person| person.kind() == kind)
+        .count()
+    };
+
+    let mut lines: Vec<String> =
+      persons.iter().map(|person| greeter.greet(person)).collect();
+
+    lines.push(format!(
+      "Greeted {} {} ({} formal, {} casual, {} anonymous).",
+      persons.len(),
+      if persons.len() == 1 {
+        "person"
+      } else {
+        "people"
+      },
+      count(PersonKind::Formal),
+      count(PersonKind::Casual),
+      count(PersonKind::Anon)
+    ));
+
+    lines.join("\n")
+  }
+
+  #[test]
+  fn test_greet_with_footer()
+  {
+    use crate::{
+      v5::make_persons,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = WithName(WordGreeter::new("Hi"));
+    let persons = make_persons();
+
+    assert_eq!(
+      greet_with_footer(&greeter, &persons),
+      "Hi, Mr. John Smith!\n\
+       Hi, Alice!\n\
+       Hi, Anonymous #8!\n\
+       Greeted 3 people (1 formal, 1 casual, 1 anonymous)."
+    );
+
+    assert!(greet_with_footer(&greeter, &persons[1..2])
+      .ends_with("Greeted 1 person (0 formal, 1 casual, 0 anonymous)."));
+  }
+}
+
+mod ids
+{
+  use std::ops::RangeInclusive;
+
+  use crate::v3::{
+    greet_many_generic,
+    Anonymous,
+    HasName,
+  };
+
+  impl HasName for RangeInclusive<u64>
+  {
+    fn name(&self) -> String
+    {
+      if self.start() == self.end() {
+        format!("Anonymous #{}", self.start())
+      } else {
+        format!("Anonymous #{} to #{}", self.start(), self.end())
+      }
+    }
+  }
+
+  pub fn greet_id_range_inclusive(range: RangeInclusive<u64>) -> Vec<String>
+  {
+    let persons: Vec<Anonymous> = range.map(Anonymous::new).collect();
+    greet_many_generic(&persons)
+  }
+
+  #[test]
+  fn test_greet_id_range_inclusive()
+  {
+    assert_eq!(
+      greet_id_range_inclusive(0..=2),
+      vec![
+        "Hello, Anonymous #0!",
+        "Hello, Anonymous #1!",
+        "Hello, Anonymous #2!"
+      ]
+    );
+
+    assert_eq!(
+      greet_id_range_inclusive(5..=5),
+      vec!["Hello, Anonymous #5!"]
+    );
+
+    assert_eq!(
+      greet_id_range_inclusive(u64::MAX - 1..=u64::MAX),
+      vec![
+        format!("Hello, Anonymous #{}!", u64::MAX - 1),
+        format!("Hello, Anonymous #{}!", u64::MAX),
+      ]
+    );
+
+    assert_eq!((0..=2).name(), "Anonymous #0 to #2");
+    assert_eq!((5..=5).name(), "Anonymous #5");
+  }
+}
+
+mod markup
+{
+  use crate::{
+    decorators::Renamed,
+    v3::HasName,
+    v8::Greeter,
+  };
+
+  pub struct MarkdownGreeter<G>
+  {
+    pub inner: G,
+  }
+
+  impl<P, G> Greeter<P> for MarkdownGreeter<G>
+  where
+    P: HasName,
+    G: for<'a> Greeter<Renamed<'a, P>>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let bold = format!("**{}**", person.name().replace('*', "\\*"));
+      self.inner.greet(&Renamed::new(person, bold))
+    }
+  }
+
+  #[test]
+  fn test_markdown_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = MarkdownGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+    };
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Alice")),
+      "Hello, **Alice**!"
+    );
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("*Star*")),
+      "Hello, **\\*Star\\***!"
+    );
+  }
+
+  #[derive(Clone, Copy)]
+  pub enum AnsiColor
+  {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+  }
+
+  impl AnsiColor
+  {
+    pub fn code(&self) -> u8
+    {
+      match self {
+        Self::Red => 31,
+        Self::Green => 32,
+        Self::Yellow => 33,
+        Self::Blue => 34,
+        Self::Magenta => 35,
+        Self::Cyan => 36,
+      }
+    }
+  }
+
+  pub struct ColorGreeter<G>
+  {
+    pub inner: G,
+    pub color: AnsiColor,
+    pub no_color: bool,
+  }
+
+  impl<P, G> Greeter<P> for ColorGreeter<G>
+  where
+    P: HasName,
+    G: for<'a> Greeter<Renamed<'a, P>>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let name = person.name();
+
+      if self.no_color {
+        self.inner.greet(&Renamed::new(person, name))
+      } else {
+        let colored = format!("\x1b[{}m{}\x1b[0m", self.color.code(), name);
+        self.inner.greet(&Renamed::new(person, colored))
+      }
+    }
+  }
+
+  #[test]
+  fn test_color_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let person = CasualPerson::new("Alice");
+
+    let greeter = ColorGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+      color: AnsiColor::Green,
+      no_color: false,
+    };
+
+    assert_eq!(greeter.greet(&person), "Hello, \x1b[32mAlice\x1b[0m!");
+
+    let greeter = ColorGreeter {
+      no_color: true,
+      ..greeter
+    };
+
+    assert_eq!(greeter.greet(&person), "Hello, Alice!");
+  }
+
+  pub struct MentionGreeter<G>
+  {
+    pub inner: G,
+    pub prefix: char,
+  }
+
+  impl<G> MentionGreeter<G>
+  {
+    pub fn new(inner: G) -> Self
+    {
+      Self { inner, prefix: '@' }
+    }
+  }
+
+  impl<P, G> Greeter<P> for MentionGreeter<G>
+  where
+    P: HasName,
+    G: for<'a> Greeter<Renamed<'a, P>>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let name = person.name();
+
+      let mention = if name.contains(char::is_whitespace) {
+        format!("{}<{}>", self.prefix, name)
+      } else {
+        format!("{}{}", self.prefix, name)
+      };
+
+      self.inner.greet(&Renamed::new(person, mention))
+    }
+  }
+
+  #[test]
+  fn test_mention_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = MentionGreeter::new(WithName(WordGreeter::new("Hey")));
+
+    assert_eq!(greeter.greet(&CasualPerson::new("Alice")), "Hey, @Alice!");
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("John Smith")),
+      "Hey, @<John Smith>!"
+    );
+
+    let greeter = MentionGreeter {
+      prefix: '#',
+      ..greeter
+    };
+
+    assert_eq!(greeter.greet(&CasualPerson::new("Alice")), "Hey, #Alice!");
+  }
+
+  pub fn mask_partial(name: &str) -> String
+  {
+    let chars: Vec<char> = name.chars().collect();
+
+    match chars.as_slice() {
+      [first, middle @ .., last] if !middle.is_empty() => {
+        format!("{}{}{}", first, "*".repeat(middle.len()), last)
+      }
+      _ => name.to_string(),
+    }
+  }
+
+  pub struct PartialMaskGreeter<G>
+  {
+    pub inner: G,
+  }
+
+  impl<P, G> Greeter<P> for PartialMaskGreeter<G>
+  where
+    P: HasName,
+    G: for<'a> Greeter<Renamed<'a, P>>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let masked = mask_partial(&person.name());
+      self.inner.greet(&Renamed::new(person, masked))
+    }
+  }
+
+  #[test]
+  fn test_partial_mask_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = PartialMaskGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+    };
+
+    assert_eq!(greeter.greet(&CasualPerson::new("Alice")), "Hello, A***e!");
+    assert_eq!(greeter.greet(&CasualPerson::new("Al")), "Hello, Al!");
+    assert_eq!(greeter.greet(&CasualPerson::new("A")), "Hello, A!");
+    assert_eq!(greeter.greet(&CasualPerson::new("Zoë")), "Hello, Z*ë!");
+  }
+}
+
+mod phonetic
+{
+  use crate::{
+    decorators::Renamed,
+    v3::HasName,
+    v8::Greeter,
+  };
+
+  fn is_vowel(c: char) -> bool
+  {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+  }
+
+  fn syllables(word: &str) -> Vec<String>
+  {
+    let mut chars: Vec<char> = word.to_lowercase().chars().collect();
+
+    if chars.len() > 2
+      && chars.last() == Some(&'e')
+      && !is_vowel(chars[chars.len() - 2])
+    {
+      chars.pop();
+    }
+
+    let mut syllables = Vec::new();
+    let mut current = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+      current.push(chars[i]);
+
+      let ends_vowel_group = is_vowel(chars[i])
+        && !matches!(chars.get(i + 1), Some(next) if is_vowel(*next));
+
+      if ends_vowel_group && i + 1 < chars.len() {
+        i += 1;
+        current.push(chars[i]);
+
+        if chars[i + 1..].iter().any(|c| is_vowel(*c)) {
+          syllables.push(std::mem::take(&mut current));
+        }
+      }
+
+      i += 1;
+    }
+
+    if !current.is_empty() {
+      syllables.push(current);
+    }
+
+    syllables
+  }
+
+  // A rough, English-centric approximation: syllables are split after the
+  // first consonant following each vowel group, a silent trailing "e" is
+  // dropped, and the first syllable of each word is stressed.
+  pub fn phonetic(name: &str) -> String
+  {
+    name
+      .split_whitespace()
+      .map(|word| {
+        let mut syllables = syllables(word);
+
+        if let Some(first) = syllables.first_mut() {
+          *first = first.to_uppercase();
+        }
+
+        syllables.join("-")
+      })
+      .collect::<Vec<_>>()
+      .join(" ")
+  }
+
+  pub struct PhoneticGreeter<G>
+  {
+    pub inner: G,
+  }
+
+  impl<P, G> Greeter<P> for PhoneticGreeter<G>
+  where
+    P: HasName,
+    G: for<'a> Greeter<Renamed<'a, P>>,
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let name = person.name();
+      let spelled = format!("{} ({})", name, phonetic(&name));
+
+      self.inner.greet(&Renamed::new(person, spelled))
+    }
+  }
+
+  #[test]
+  fn test_phonetic_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    assert_eq!(phonetic("Alice"), "AL-ic");
+    assert_eq!(phonetic("Robert"), "ROB-ert");
+    assert_eq!(phonetic("Bob"), "BOB");
+    assert_eq!(phonetic("Ana Maria"), "AN-a MAR-ia");
+
+    let greeter = PhoneticGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+    };
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Alice")),
+      "Hello, Alice (AL-ic)!"
+    );
+  }
+}
+
+mod age
+{
+  use crate::{
+    decorators::Renamed,
+    v3::{
+      Anonymous,
+      CasualPerson,
+      FormalPerson,
+      HasName,
+    },
+    v7,
+    v8::Greeter,
+  };
+
+  pub trait HasAge
+  {
+    fn age(&self) -> Option<u32>;
+  }
+
+  #[derive(Clone, PartialEq, Eq, Hash)]
+  pub struct Aged<P>
+  {
+    pub person: P,
+    pub age: Option<u32>,
+  }
+
+  impl<P: HasName> HasName for Aged<P>
+  {
+    fn name(&self) -> String
+    {
+      self.person.name()
+    }
+  }
+
+  impl<P> HasAge for Aged<P>
+  {
+    fn age(&self) -> Option<u32>
+    {
+      self.age
+    }
+  }
+
+  impl<'a, P: HasAge> HasAge for Renamed<'a, P>
+  {
+    fn age(&self) -> Option<u32>
+    {
+      self.person.age()
+    }
+  }
+
+  impl HasAge for FormalPerson
+  {
+    fn age(&self) -> Option<u32>
+    {
+      self.age
+    }
+  }
+
+  impl HasAge for CasualPerson
+  {
+    fn age(&self) -> Option<u32>
+    {
+      self.age
+    }
+  }
+
+  impl HasAge for Anonymous
+  {
+    fn age(&self) -> Option<u32>
+    {
+      self.age
+    }
+  }
+
+  #[derive(Clone, Copy, Debug, PartialEq)]
+  pub enum AgeBracket
+  {
+    Child,
+    Adult,
+    Senior,
+  }
+
+  impl AgeBracket
+  {
+    pub fn from_age(age: u32) -> Self
+    {
+      match age {
+        0..=17 => Self::Child,
+        18..=64 => Self::Adult,
+        _ => Self::Senior,
+      }
+    }
+  }
+
+  pub struct AgeGreeter;
+
+  impl<P: HasName + HasAge> Greeter<P> for AgeGreeter
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      match person.age().map(AgeBracket::from_age) {
+        Some(AgeBracket::Child) => format!("Hi there, {}!", person.name()),
+        Some(AgeBracket::Adult) => format!("Hello, {}.", person.name()),
+        Some(AgeBracket::Senior) => format!("Good day, {}.", person.name()),
+        None => format!("Hello, {}!", person.name()),
+      }
+    }
+  }
+
+  #[test]
+  fn test_age_greeter()
+  {
+    use crate::v3::CasualPerson;
+
+    let aged = |age| Aged {
+      person: CasualPerson::new("Alice"),
+      age,
+    };
+
+    assert_eq!(AgeGreeter.greet(&aged(Some(9))), "Hi there, Alice!");
+    assert_eq!(AgeGreeter.greet(&aged(Some(30))), "Hello, Alice.");
+    assert_eq!(AgeGreeter.greet(&aged(Some(80))), "Good day, Alice.");
+    assert_eq!(AgeGreeter.greet(&aged(None)), "Hello, Alice!");
+  }
+
+  pub struct MinorAdultGreeter;
+
+  impl<P: HasName + HasAge> v7::Greeter<P> for MinorAdultGreeter
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      match person.age() {
+        Some(age) if age < 18 => format!("Hey there, {}!", person.name()),
+        Some(_) => format!("Good day, {}.", person.name()),
+        None => format!("Hello, {}!", person.name()),
+      }
+    }
+  }
+
+  #[test]
+  fn test_minor_adult_greeter()
+  {
+    use crate::v7::Greeter as _;
+
+    let aged = |age| Aged {
+      person: CasualPerson::new("Alice"),
+      age,
+    };
+
+    assert_eq!(MinorAdultGreeter.greet(&aged(Some(9))), "Hey there, Alice!");
+    assert_eq!(MinorAdultGreeter.greet(&aged(Some(18))), "Good day, Alice.");
+    assert_eq!(MinorAdultGreeter.greet(&aged(None)), "Hello, Alice!");
+    assert_eq!(
+      MinorAdultGreeter.greet(&CasualPerson::new("Bob")),
+      "Hello, Bob!"
+    );
+
+    let mut bob = CasualPerson::new("Bob");
+    bob.age = Some(12);
+
+    assert_eq!(MinorAdultGreeter.greet(&bob), "Hey there, Bob!");
+
+    let mut smith = FormalPerson::new("Mr.", "John", "Smith");
+    smith.age = Some(40);
+
+    assert_eq!(MinorAdultGreeter.greet(&smith), "Good day, Mr. John Smith.");
+  }
+}
+
+mod visit
+{
+  use std::{
+    cell::RefCell,
+    collections::HashMap,
+  };
+
+  use crate::{
+    v3::HasName,
+    v8::Greeter,
+  };
+
+  pub fn ordinal(n: usize) -> String
+  {
+    let suffix = match (n % 10, n % 100) {
+      (_, 11..=13) => "th",
+      (1, _) => "st",
+      (2, _) => "nd",
+      (3, _) => "rd",
+      _ => "th",
+    };
+
+    format!("{}{}", n, suffix)
+  }
+
+  pub struct VisitGreeter<G>
+  {
+    pub inner: G,
+    visits: RefCell<HashMap<String, usize>>,
+  }
+
+  impl<G> VisitGreeter<G>
+  {
+    pub fn new(inner: G) -> Self
+    {
+      Self {
+        inner,
+        visits: RefCell::new(HashMap::new()),
+      }
+    }
+  }
+
+  impl<P: HasName, G: Greeter<P>> Greeter<P> for VisitGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let visit = {
+        let mut visits = self.visits.borrow_mut();
+        let count = visits.entry(person.name()).or_insert(0);
+        *count += 1;
+        *count
+      };
+
+      format!(
+        "{} This is your {} visit.",
+        self.inner.greet(person),
+        ordinal(visit)
+      )
+    }
+  }
+
+  #[test]
+  fn test_visit_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = VisitGreeter::new(WithName(WordGreeter::new("Welcome")));
+    let alice = CasualPerson::new("Alice");
+
+    assert_eq!(
+      greeter.greet(&alice),
+      "Welcome, Alice! This is your 1st visit."
+    );
+    assert_eq!(
+      greeter.greet(&alice),
+      "Welcome, Alice! This is your 2nd visit."
+    );
+    assert_eq!(
+      greeter.greet(&alice),
+      "Welcome, Alice! This is your 3rd visit."
+    );
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Bob")),
+      "Welcome, Bob! This is your 1st visit."
+    );
+
+    assert_eq!(ordinal(11), "11th");
+    assert_eq!(ordinal(22), "22nd");
+    assert_eq!(ordinal(113), "113th");
+  }
+}
+
+mod time
+{
+  use crate::{
+    v3::HasName,
+    v8::{
+      Greeter,
+      NameGreeter,
+    },
+  };
+
+  #[derive(Clone, Copy, Debug, PartialEq)]
+  pub enum TimeOfDay
+  {
+    Morning,
+    Afternoon,
+    Evening,
+  }
+
+  impl TimeOfDay
+  {
+    pub fn from_hour(hour: u32) -> Self
+    {
+      match hour % 24 {
+        5..=11 => Self::Morning,
+        12..=17 => Self::Afternoon,
+        _ => Self::Evening,
+      }
+    }
+
+    pub fn salutation(&self) -> &'static str
+    {
+      match self {
+        Self::Morning => "Good morning",
+        Self::Afternoon => "Good afternoon",
+        Self::Evening => "Good evening",
+      }
+    }
+  }
+
+  pub struct TzLabelGreeter<G>
+  {
+    pub inner: G,
+    pub hour: u32,
+    pub label: String,
+  }
+
+  impl<P, G: Greeter<P>> Greeter<P> for TzLabelGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      format!(
+        "{} ({}: {:02}:00)",
+        self.inner.greet(person),
+        self.label,
+        self.hour % 24
+      )
+    }
+  }
+
+  #[test]
+  fn test_tz_label_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    let greeter = TzLabelGreeter {
+      inner: WithName(TimeOfDayGreeter {
+        time: TimeOfDay::from_hour(9),
+      }),
+      hour: 9,
+      label: "your local time".to_string(),
+    };
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Alice")),
+      "Good morning, Alice! (your local time: 09:00)"
+    );
+
+    let greeter = TzLabelGreeter {
+      inner: WithName(WordGreeter::new("Welcome")),
+      hour: 21,
+      label: "Berlin".to_string(),
+    };
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Alice")),
+      "Welcome, Alice! (Berlin: 21:00)"
+    );
+  }
+
+  pub struct TimeOfDayGreeter
+  {
+    pub time: TimeOfDay,
+  }
+
+  impl NameGreeter for TimeOfDayGreeter
+  {
+    fn greet_name(
+      &self,
+      person: &impl HasName,
+    ) -> String
+    {
+      format!("{}, {}!", self.time.salutation(), person.name())
+    }
+  }
+
+  #[test]
+  fn test_time_of_day_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v8::WithName,
+    };
+
+    let person = CasualPerson::new("Alice");
+    let greet = |time| WithName(TimeOfDayGreeter { time }).greet(&person);
+
+    assert_eq!(greet(TimeOfDay::Morning), "Good morning, Alice!");
+    assert_eq!(greet(TimeOfDay::Afternoon), "Good afternoon, Alice!");
+    assert_eq!(greet(TimeOfDay::Evening), "Good evening, Alice!");
+  }
+}
+
+mod balanced
+{
+  use std::cell::RefCell;
+
+  use crate::{
+    v3::HasName,
+    v8::Greeter,
+  };
+
+  pub struct BalancedWordGreeter
+  {
+    pub words: Vec<String>,
+    pub counts: RefCell<Vec<usize>>,
+  }
+
+  impl BalancedWordGreeter
+  {
+    pub fn new(words: &[&str]) -> Self
+    {
+      Self {
+        words: words.iter().map(|word| word.to_string()).collect(),
+        counts: RefCell::new(vec![0; words.len()]),
+      }
+    }
+  }
+
+  impl<P: HasName> Greeter<P> for BalancedWordGreeter
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let mut counts = self.counts.borrow_mut();
+
+      let least_used = counts
+        .iter()
+        .enumerate()
+        .min_by_key(|(i, count)| (**count, *i))
+        .map(|(i, _)| i);
+
+      match least_used {
+        Some(i) => {
+          counts[i] += 1;
+          format!("{}, {}!", self.words[i], person.name())
+        }
+        None => format!("Hello, {}!", person.name()),
+      }
+    }
+  }
+
+  #[test]
+  fn test_balanced_word_greeter()
+  {
+    use crate::v3::CasualPerson;
+
+    let greeter = BalancedWordGreeter::new(&["Hi", "Hey"]);
+
+    let greetings: Vec<String> = ["A", "B", "C", "D", "E", "F"]
+      .iter()
+      .map(|name| greeter.greet(&CasualPerson::new(name)))
+      .collect();
+
+    assert_eq!(greetings[..2], ["Hi, A!", "Hey, B!"]);
+    assert_eq!(*greeter.counts.borrow(), vec![3, 3]);
+  }
+}
+
+mod salutation
+{
+  use crate::{
+    v3::CasualPerson,
+    v8::Greeter,
+  };
+
+  // A deliberately tiny heuristic table. Anything not listed, including
+  // names that are commonly unisex, falls back to the neutral "Mx.".
+  const SALUTATIONS: &[(&str, &str)] = &[
+    ("John", "Mr."),
+    ("James", "Mr."),
+    ("Robert", "Mr."),
+    ("Michael", "Mr."),
+    ("David", "Mr."),
+    ("Mary", "Ms."),
+    ("Alice", "Ms."),
+    ("Jane", "Ms."),
+    ("Elizabeth", "Ms."),
+    ("Sarah", "Ms."),
+  ];
+
+  pub fn infer_salutation(name: &str) -> &'static str
+  {
+    let first_name = name.split_whitespace().next().unwrap_or("");
+
+    SALUTATIONS
+      .iter()
+      .find(|(known, _)| known.eq_ignore_ascii_case(first_name))
+      .map(|(_, salutation)| *salutation)
+      .unwrap_or("Mx.")
+  }
+
+  pub struct InferringGreeter<G>
+  {
+    pub inner: G,
+  }
+
+  impl<G: Greeter<CasualPerson>> Greeter<CasualPerson> for InferringGreeter<G>
+  {
+    fn greet(
+      &self,
+      person: &CasualPerson,
+    ) -> String
+    {
+      let name = format!("{} {}", infer_salutation(&person.name), person.name);
+      self.inner.greet(&CasualPerson::new(&name))
+    }
+  }
+
+  #[test]
+  fn test_inferring_greeter()
+  {
+    use crate::{
+      v6::WordGreeter,
+      v8::WithName,
+    };
+
+    assert_eq!(infer_salutation("John Smith"), "Mr.");
+    assert_eq!(infer_salutation("alice"), "Ms.");
+    assert_eq!(infer_salutation("Quinn"), "Mx.");
+
+    let greeter = InferringGreeter {
+      inner: WithName(WordGreeter::new("Hello")),
+    };
+
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Jane")),
+      "Hello, Ms. Jane!"
+    );
+    assert_eq!(
+      greeter.greet(&CasualPerson::new("Quinn")),
+      "Hello, Mx. Quinn!"
+    );
+  }
+}
+
+pub mod coproduct
+{
+  use std::marker::PhantomData;
+
+  use crate::v5::Either;
+
+  pub struct Here;
+
+  pub struct There<Index>(PhantomData<Index>);
+
+  pub trait Inject<T, Index>
+  {
+    fn inject(value: T) -> Self;
+  }
+
+  impl<T> Inject<T, Here> for T
+  {
+    fn inject(value: T) -> Self
+    {
+      value
+    }
+  }
+
+  impl<T, Rest> Inject<T, Here> for Either<T, Rest>
+  {
+    fn inject(value: T) -> Self
+    {
+      Either::Left(value)
+    }
+  }
+
+  impl<T, Head, Rest, Index> Inject<T, There<Index>> for Either<Head, Rest>
+  where
+    Rest: Inject<T, Index>,
+  {
+    fn inject(value: T) -> Self
+    {
+      Either::Right(Rest::inject(value))
+    }
+  }
+
+  /// Injects a value into the coproduct position matching its type.
+  ///
+  /// Injecting a type that is not part of the coproduct fails to compile:
+  ///
+  /// ```compile_fail
+  /// use generic_greet::{
+  ///   inject,
+  ///   v5::AnyPersonGeneric,
+  /// };
+  ///
+  /// let person: AnyPersonGeneric = inject!(AnyPersonGeneric, "Alice");
+  /// ```
+  #[macro_export]
+  macro_rules! inject {
+    ($coproduct:ty, $value:expr $(,)?) => {
+      <$coproduct as $crate::coproduct::Inject<_, _>>::inject($value)
+    };
+  }
+
+  #[macro_export]
+  macro_rules! coproduct {
+    ($a:ty, $b:ty $(,)?) => {
+      $crate::v5::Either<$a, $b>
+    };
+    ($a:ty, $($rest:ty),+ $(,)?) => {
+      $crate::v5::Either<$a, $crate::coproduct![$($rest),+]>
+    };
+  }
+
+  #[macro_export]
+  macro_rules! coproduct_value {
+    (_, $($rest:tt)+) => {
+      $crate::v5::Either::Right($crate::coproduct_value!($($rest)+))
+    };
+    ($value:expr, $(_),+ $(,)?) => {
+      $crate::v5::Either::Left($value)
+    };
+    ($value:expr $(,)?) => {
+      $value
+    };
+  }
+
+  #[test]
+  fn test_coproduct()
+  {
+    use crate::{
+      v3::{
+        Anonymous,
+        CasualPerson,
+        FormalPerson,
+        HasName,
+      },
+      v5::{
+        AnyPersonGeneric,
+        Either,
+      },
+    };
+
+    let formal: coproduct![FormalPerson, CasualPerson, Anonymous] =
+      coproduct_value![FormalPerson::new("Mr.", "John", "Smith"), _, _];
+    let casual: AnyPersonGeneric =
+      coproduct_value![_, CasualPerson::new("Alice"), _];
+    let anon: coproduct![FormalPerson, CasualPerson, Anonymous] =
+      coproduct_value![_, _, Anonymous::new(8)];
+
+    let persons: Vec<AnyPersonGeneric> = vec![formal, casual, anon];
+
+    assert_eq!(
+      persons.iter().map(HasName::name).collect::<Vec<_>>(),
+      vec!["Mr. John Smith", "Alice", "Anonymous #8"]
+    );
+
+    let wide: coproduct![u8, u16, u32, u64] = coproduct_value![_, _, 3u32, _];
+
+    assert!(matches!(
+      wide,
+      Either::Right(Either::Right(Either::Left(3)))
+    ));
+  }
+
+  #[test]
+  fn test_inject()
+  {
+    use crate::{
+      v3::{
+        Anonymous,
+        CasualPerson,
+        FormalPerson,
+        HasName,
+      },
+      v5::{
+        make_persons,
+        AnyPersonGeneric,
+      },
+    };
+
+    let persons: Vec<AnyPersonGeneric> = vec![
+      inject!(AnyPersonGeneric, FormalPerson::new("Mr.", "John", "Smith")),
+      inject!(AnyPersonGeneric, CasualPerson::new("Alice")),
+      inject!(AnyPersonGeneric, Anonymous::new(8)),
+    ];
+
+    assert!(persons[1].right_ref().is_some_and(Either::is_left));
+    assert_eq!(
+      persons.iter().map(HasName::name).collect::<Vec<_>>(),
+      make_persons().iter().map(HasName::name).collect::<Vec<_>>()
+    );
+  }
+}
+
+mod marker
+{
+  use std::marker::PhantomData;
+
+  use crate::{
+    v3::HasName,
+    v8::NameGreeter,
+  };
+
+  pub trait GreetWord
+  {
+    const WORD: &'static str;
+  }
+
+  pub struct Hello;
+
+  pub struct Hi;
+
+  pub struct Welcome;
+
+  impl GreetWord for Hello
+  {
+    const WORD: &'static str = "Hello";
+  }
+
+  impl GreetWord for Hi
+  {
+    const WORD: &'static str = "Hi";
+  }
+
+  impl GreetWord for Welcome
+  {
+    const WORD: &'static str = "Welcome";
+  }
+
+  pub struct MarkerGreeter<W>(PhantomData<W>);
+
+  impl<W> MarkerGreeter<W>
+  {
+    pub fn new() -> Self
+    {
+      MarkerGreeter(PhantomData)
+    }
+  }
+
+  impl<W> Default for MarkerGreeter<W>
+  {
+    fn default() -> Self
+    {
+      Self::new()
+    }
+  }
+
+  impl<W: GreetWord> NameGreeter for MarkerGreeter<W>
+  {
+    fn greet_name(
+      &self,
+      person: &impl HasName,
+    ) -> String
+    {
+      format!("{}, {}!", W::WORD, person.name())
+    }
+  }
+
+  #[test]
+  fn test_marker_greeter()
+  {
+    use crate::{
+      v3::CasualPerson,
+      v8::{
+        Greeter,
+        WithName,
+      },
+    };
+
+    let person = CasualPerson::new("Alice");
+
+    let greeter = WithName(MarkerGreeter::<Hi>::new());
+    assert_eq!(greeter.greet(&person), "Hi, Alice!");
+
+    let greeter = WithName(MarkerGreeter::<Welcome>::new());
+    assert_eq!(greeter.greet(&person), "Welcome, Alice!");
+
+    assert_eq!(std::mem::size_of::<MarkerGreeter<Hello>>(), 0);
+  }
+}
+
+mod email
+{
+  use crate::{
+    decorators::Renamed,
+    v3::{
+      Anonymous,
+      CasualPerson,
+      FormalPerson,
+      HasName,
+    },
+    v7::Greeter,
+  };
+
+  pub trait HasEmail
+  {
+    fn email(&self) -> Option<String>;
+  }
+
+  impl<'a, P: HasEmail> HasEmail for Renamed<'a, P>
+  {
+    fn email(&self) -> Option<String>
+    {
+      self.person.email()
+    }
+  }
+
+  fn local_part(name: &str) -> String
+  {
+    name
+      .split_whitespace()
+      .collect::<Vec<_>>()
+      .join(".")
+      .to_lowercase()
+  }
+
+  impl HasEmail for FormalPerson
+  {
+    fn email(&self) -> Option<String>
+    {
+      Some(format!(
+        "{}.{}@example.com",
+        local_part(&self.first_name),
+        local_part(&self.last_name)
+      ))
+    }
+  }
+
+  impl HasEmail for CasualPerson
+  {
+    fn email(&self) -> Option<String>
+    {
+      Some(format!("{}@example.com", local_part(&self.name)))
+    }
+  }
+
+  impl HasEmail for Anonymous
+  {
+    fn email(&self) -> Option<String>
+    {
+      None
+    }
+  }
+
+  pub struct EmailGreeter;
+
+  impl<P: HasName + HasEmail> Greeter<P> for EmailGreeter
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      match person.email() {
+        Some(email) => {
+          format!("Hello, {}! We'll reach you at {}.", person.name(), email)
+        }
+        None => format!("Hello, {}!", person.name()),
+      }
+    }
+  }
+
+  #[test]
+  fn test_email_greeter()
+  {
+    assert_eq!(
+      EmailGreeter.greet(&FormalPerson::new("Mr.", "John", "Smith")),
+      "Hello, Mr. John Smith! We'll reach you at john.smith@example.com."
+    );
+    assert_eq!(
+      EmailGreeter.greet(&CasualPerson::new("Alice")),
+      "Hello, Alice! We'll reach you at alice@example.com."
+    );
+    assert_eq!(
+      CasualPerson::new("Alice  Liddell").email(),
+      Some("alice.liddell@example.com".to_string())
+    );
+    assert_eq!(
+      FormalPerson::new("Mr.", "Dick", "Van Dyke").email(),
+      Some("dick.van.dyke@example.com".to_string())
+    );
+    assert_eq!(
+      EmailGreeter.greet(&Anonymous::new(8)),
+      "Hello, Anonymous #8!"
+    );
+  }
+}
+
+mod pronoun
+{
+  use crate::{
+    decorators::Renamed,
+    v3::{
+      Anonymous,
+      CasualPerson,
+      FormalPerson,
+      HasName,
+    },
+    v8::Greeter,
+  };
+
+  #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+  pub enum Pronoun
+  {
+    He,
+    She,
+    They,
+  }
+
+  impl Pronoun
+  {
+    pub fn subject(self) -> &'static str
+    {
+      match self {
+        Self::He => "he",
+        Self::She => "she",
+        Self::They => "they",
+      }
+    }
+
+    pub fn possessive(self) -> &'static str
+    {
+      match self {
+        Self::He => "his",
+        Self::She => "her",
+        Self::They => "their",
+      }
+    }
+
+    // Singular "they" still takes the plural verb form: "they enjoy".
+    pub fn conjugate(
+      self,
+      verb: &str,
+    ) -> String
+    {
+      match self {
+        Self::They => verb.to_string(),
+        Self::He | Self::She => format!("{}s", verb),
+      }
+    }
+  }
+
+  pub trait HasPronoun
+  {
+    fn pronoun(&self) -> Pronoun
+    {
+      Pronoun::They
+    }
+  }
+
+  impl<'a, P: HasPronoun> HasPronoun for Renamed<'a, P>
+  {
+    fn pronoun(&self) -> Pronoun
+    {
+      self.person.pronoun()
+    }
+  }
+
+  impl HasPronoun for FormalPerson {}
+
+  impl HasPronoun for CasualPerson {}
+
+  impl HasPronoun for Anonymous {}
+
+  #[derive(Clone, PartialEq, Eq, Hash)]
+  pub struct WithPronoun<P>
+  {
+    pub person: P,
+    pub pronoun: Pronoun,
+  }
+
+  impl<P: HasName> HasName for WithPronoun<P>
+  {
+    fn name(&self) -> String
+    {
+      self.person.name()
+    }
+  }
+
+  impl<P> HasPronoun for WithPronoun<P>
+  {
+    fn pronoun(&self) -> Pronoun
+    {
+      self.pronoun
+    }
+  }
+
+  pub struct PronounGreeter;
+
+  impl<P: HasName + HasPronoun> Greeter<P> for PronounGreeter
+  {
+    fn greet(
+      &self,
+      person: &P,
+    ) -> String
+    {
+      let pronoun = person.pronoun();
+
+      format!(
+        "Welcome {}! We hope {} {} {} stay.",
+        person.name(),
+        pronoun.subject(),
+        pronoun.conjugate("enjoy"),
+        pronoun.possessive()
+      )
+    }
+  }
+
+  #[test]
+  fn test_pronoun_greeter()
+  {
+    let with = |name, pronoun| WithPronoun {
+      person: CasualPerson::new(name),
+      pronoun,
+    };
+
+    assert_eq!(
+      PronounGreeter.greet(&with("Bob", Pronoun::He)),
+      "Welcome Bob! We hope he enjoys his stay."
+    );
+    assert_eq!(
+      PronounGreeter.greet(&with("Alice", Pronoun::She)),
+      "Welcome Alice! We hope she enjoys her stay."
+    );
+    assert_eq!(
+      PronounGreeter.greet(&with("Sam", Pronoun::They)),
+      "Welcome Sam! We hope they enjoy their stay."
+    );
+    assert_eq!(
+      PronounGreeter.greet(&Anonymous::new(8)),
+      "Welcome Anonymous #8! We hope they enjoy their stay."
+    );
+  }
+}
+
+mod farewell
+{
+  use crate::{
+    v3::{
+      Anonymous,
+      CasualPerson,
+      FormalPerson,
+      HasName,
+    },
+    v5::{
+      AnyPerson,
+      AnyPersonGeneric,
+      Either,
+    },
+  };
+
+  pub trait Farewell<Person>
+  {
+    fn farewell(
+      &self,
+      person: &Person,
+    ) -> String;
+  }
+
+  pub fn farewell_many<P, F: Farewell<P>>(
+    farewell: &F,
+    persons: &[P],
+  ) -> Vec<String>
+  {
+    persons
+      .iter()
+      .map(|person| farewell.farewell(person))
+      .collect()
+  }
+
+  impl<F, A, B> Farewell<Either<A, B>> for F
+  where
+    F: Farewell<A>,
+    F: Farewell<B>,
+  {
+    fn farewell(
+      &self,
+      person: &Either<A, B>,
+    ) -> String
+    {
+      match person {
+        Either::Left(person) => self.farewell(person),
+        Either::Right(person) => self.farewell(person),
+      }
+    }
+  }
+
+  impl<F> Farewell<AnyPerson> for F
+  where
+    F: Farewell<AnyPersonGeneric>,
+  {
+    fn farewell(
+      &self,
+      person: &AnyPerson,
+    ) -> String
+    {
+      self.farewell(&person.0)
+    }
+  }
+
+  pub struct StandardFarewell;
+
+  impl Farewell<FormalPerson> for StandardFarewell
+  {
+    fn farewell(
+      &self,
+      person: &FormalPerson,
+    ) -> String
+    {
+      format!("Goodbye, {}!", person.name())
+    }
+  }
+
+  impl Farewell<CasualPerson> for StandardFarewell
+  {
+    fn farewell(
+      &self,
+      person: &CasualPerson,
+    ) -> String
+    {
+      format!("Goodbye, {}!", person.name())
+    }
+  }
+
+  impl Farewell<Anonymous> for StandardFarewell
+  {
+    fn farewell(
+      &self,
+      person: &Anonymous,
+    ) -> String
+    {
+      format!("Goodbye, {}!", person.name())
+    }
+  }
+
+  #[test]
+  fn test()
+  {
+    use crate::v5::make_persons;
+
+    let persons = make_persons();
+
+    assert_eq!(
+      farewell_many(&StandardFarewell, &persons),
+      vec![
+        "Goodbye, Mr. John Smith!",
+        "Goodbye, Alice!",
+        "Goodbye, Anonymous #8!"
+      ]
+    );
+  }
+}